@@ -41,10 +41,10 @@ race_fusion_symmetry(R1, R2, RR) :- fuse_race(R1, R2, RR); fuse_race(R2, R1, RR)
  * - Its base level must be at least the average level of the input demons.
  * - It must be the lowest-level demon in the knowledge base that satisfies these conditions.
  */  
-normal_fusion(demon(Name1, Race1, Lv1), demon(Name2, Race2, Lv2), demon(NameR, RaceR, LvR, false)) :- 
+normal_fusion(demon(Name1, Race1, Lv1, Special1), demon(Name2, Race2, Lv2, Special2), demon(NameR, RaceR, LvR, false)) :- 
     % Set the demon 1 and the demon 2
-    demon(Name1, Race1, Lv1),
-    demon(Name2, Race2, Lv2),
+    demon(Name1, Race1, Lv1, Special1),
+    demon(Name2, Race2, Lv2, Special2),
     % find the race of the resulting demon
     race_fusion_symmetry(Race1, Race2, RaceR), 
     % calculate the level of the resulting demon
@@ -61,6 +61,16 @@ normal_fusion(demon(Name1, Race1, Lv1), demon(Name2, Race2, Lv2), demon(NameR, R
  */
 min_lv_above_avg(Race, AvgLv, MinLv):- findall(Lv, (demon(_, Race, Lv, false), Lv #>= AvgLv), S), list_min(S, MinLv).
 
+/**
+ * Describes the sacrificial fusion of two demons with a third demon, only possible during the full phase of Kagutsuchi.
+ * The resulting demon is the one of the normal fusion of the two demons,
+ * and the sacrificed demon transfers its experience and skills to it.
+ */
+sacrificial_fusion(DemonI, DemonJ, demon(NameK, RaceK, LvK, SpecialK), DemonR) :-
+    phase_condition(sacrificial_fusion, 8),
+    demon(NameK, RaceK, LvK, SpecialK),
+    normal_fusion(DemonI, DemonJ, DemonR).
+
 /**
  * A normal fusion step.
  */
@@ -100,6 +110,33 @@ plan(D0, demon(NameR, RaceR, LvR, _)) -->
     },
     plan(D4, demon(NameR, RaceR, LvR, _)).
 
+/**
+ * Describe a fusion plan starting with a sacrificial fusion.
+ * - The demon set is `D0`.
+ * - The desired demon is `demon(NameR, RaceR, LvR, _)`.
+ */
+plan(D0, demon(NameR, RaceR, LvR, _)) --> 
+    [sacrificial_fusion(DemonI, DemonJ, DemonK)],
+    {
+        % Make sure there are no duplicate
+        list_to_set(D0, D1),
+        % two demons have the to be fused with a third sacrificed demon
+        member(DemonI, D1),
+        member(DemonJ, D1),
+        member(DemonK, D1),
+        dif(DemonI, DemonJ),
+        dif(DemonI, DemonK),
+        dif(DemonJ, DemonK),
+        sacrificial_fusion(DemonI, DemonJ, DemonK, DemonR),
+        % the next fusion pool cannot have already fused or sacrificed demons
+        select(DemonI, D1, D2),
+        select(DemonJ, D2, D3),
+        select(DemonK, D3, D4),
+        % the next fusion pool have the fused demon
+        append(D4, [DemonR], D5) 
+    },
+    plan(D5, demon(NameR, RaceR, LvR, _)).
+
 /**
  * Describe the shortest fusion plan.
  * - The demon set is `D0`.
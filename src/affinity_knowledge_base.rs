@@ -0,0 +1,239 @@
+// smt-nocturne-prolog-kb-generator: A generates of a Prolog knowledge base
+// to describe demons, their fusion, and fusion mechanics for the game 
+// Shin Megami Tensei III: Nocturne.
+// Copyright (C) 2025  Bryan-Elliott Tam
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::dataset::Dataset;
+use crate::model::*;
+use crate::prolog::*;
+use crate::util::*;
+use anyhow::Error;
+use oxigraph::sparql::QuerySolution;
+
+/// Query the elemental affinities of the Shin Megami Tensei Nocture demons from a dataset.
+///
+/// Returns an error when an affinity is not one of the affinities of the vocabulary.
+pub fn query_affinities(dataset: &Dataset) -> Result<Vec<DemonAffinity>, Error> {
+    query_model(dataset, GET_AFFINITY_QUERY, affinity_from_solution)
+}
+
+/// create a prolog knowledge based containing the elemental affinities of Shin Megami Tensei Nocture demons.
+pub fn create_prolog_affinity_knowledge_base(
+    affinities: &[DemonAffinity],
+    name_representation: NameRepresentation,
+) -> Vec<PrologTerm> {
+    create_prolog_knowledge_base(affinities, |affinity| {
+        generate_a_prolog_fact(affinity, name_representation)
+    })
+}
+
+fn affinity_from_solution(solution_map: QuerySolution) -> Result<DemonAffinity, Error> {
+    let name = solution_variable(&solution_map, "name")?;
+    let element = solution_variable(&solution_map, "element")?;
+    let kind = solution_variable(&solution_map, "kind")?;
+
+    Ok(DemonAffinity {
+        demon: literal_string_to_string(name, "name")?,
+        element: literal_string_to_string(element, "element")?,
+        affinity: Affinity::from_iri(&named_node_to_string(kind, "kind")?)?,
+    })
+}
+
+fn generate_a_prolog_fact(
+    affinity: &DemonAffinity,
+    name_representation: NameRepresentation,
+) -> PrologTerm {
+    PrologTerm::compound(
+        "affinity",
+        vec![
+            name_representation.term(&affinity.demon),
+            name_representation.term(&affinity.element),
+            PrologTerm::Atom(affinity.affinity.name().to_string()),
+        ],
+    )
+}
+
+const GET_AFFINITY_QUERY: &str = "
+PREFIX vocab: <https://constraintautomaton.github.io/smt-nocture-db-to-rdf/vocabulary.ttl#>
+PREFIX schema: <https://schema.org/>
+
+SELECT ?name ?element ?kind WHERE {
+    ?demon schema:name ?name ;
+        vocab:hasAffinity ?affinity .
+
+    ?affinity vocab:affinityElement ?element ;
+        vocab:affinityKind ?kind .
+}";
+
+#[cfg(test)]
+mod affinity_from_solution_test {
+    use super::*;
+    use oxigraph::model::*;
+
+    #[test]
+    fn should_return_an_error_given_the_kind_is_not_a_named_node() -> Result<(), Error> {
+        let solution_map: QuerySolution = QuerySolution::from((
+            vec![
+                Variable::new("name")?,
+                Variable::new("element")?,
+                Variable::new("kind")?,
+            ],
+            vec![
+                Term::Literal(Literal::from("a")).into(),
+                Term::Literal(Literal::from("Fire")).into(),
+                Term::Literal(Literal::from("Resist")).into(),
+            ],
+        ));
+
+        let res = affinity_from_solution(solution_map);
+
+        assert_eq!(
+            res.err().map(|error| error.to_string()),
+            Some("the value of the variable 'kind' is not a named node".to_string())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_an_error_given_an_unknown_kind() -> Result<(), Error> {
+        let solution_map: QuerySolution = QuerySolution::from((
+            vec![
+                Variable::new("name")?,
+                Variable::new("element")?,
+                Variable::new("kind")?,
+            ],
+            vec![
+                Term::Literal(Literal::from("a")).into(),
+                Term::Literal(Literal::from("Fire")).into(),
+                Term::NamedNode(NamedNode::new("http://example.com/Absorb")?).into(),
+            ],
+        ));
+
+        let res = affinity_from_solution(solution_map);
+
+        assert_eq!(
+            res.err().map(|error| error.to_string()),
+            Some("unknown affinity 'http://example.com/Absorb'".to_string())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_an_affinity_given_a_valid_solution_map() -> Result<(), Error> {
+        let solution_map: QuerySolution = QuerySolution::from((
+            vec![
+                Variable::new("name")?,
+                Variable::new("element")?,
+                Variable::new("kind")?,
+            ],
+            vec![
+                Term::Literal(Literal::from("a")).into(),
+                Term::Literal(Literal::from("Fire")).into(),
+                Term::NamedNode(NamedNode::new(Affinity::Repel.iri())?).into(),
+            ],
+        ));
+
+        let res = affinity_from_solution(solution_map)?;
+
+        assert_eq!(
+            res,
+            DemonAffinity {
+                demon: "a".to_string(),
+                element: "Fire".to_string(),
+                affinity: Affinity::Repel,
+            }
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod generate_a_prolog_fact_test {
+    use super::*;
+
+    #[test]
+    fn should_return_a_prolog_fact() {
+        let affinity = DemonAffinity {
+            demon: "a".to_string(),
+            element: "Fire".to_string(),
+            affinity: Affinity::Drain,
+        };
+
+        let res = generate_a_prolog_fact(&affinity, NameRepresentation::Atom);
+        assert_eq!(res.to_fact(), "affinity('a', 'Fire', drain).".to_string());
+
+        let res = generate_a_prolog_fact(&affinity, NameRepresentation::String);
+        assert_eq!(
+            res.to_fact(),
+            "affinity(\"a\", \"Fire\", drain).".to_string()
+        );
+    }
+}
+
+#[cfg(test)]
+mod query_affinities_test {
+    use super::*;
+    use crate::error::ErrorUnknownAffinity;
+    use std::collections::HashSet;
+    use std::path::PathBuf;
+
+    #[test]
+    fn should_return_a_prolog_knowledge_base() -> Result<(), Error> {
+        let demon_file_path = PathBuf::from("./test_files/test_valid_demon.ttl");
+        let affinity_file_path = PathBuf::from("./test_files/test_valid_affinity.ttl");
+        let expected_knowledge_base: HashSet<String> = vec![
+            "affinity('Abaddon', 'Death', null).".to_string(),
+            "affinity('Abaddon', 'Expel', weak).".to_string(),
+            "affinity('Aeros', 'Elec', weak).".to_string(),
+        ]
+        .into_iter()
+        .collect();
+
+        let affinities = Dataset::load(&[&demon_file_path, &affinity_file_path])
+            .and_then(|dataset| query_affinities(&dataset))?;
+        let resp = create_prolog_affinity_knowledge_base(&affinities, NameRepresentation::Atom);
+
+        assert_eq!(
+            resp.iter()
+                .map(PrologTerm::to_fact)
+                .collect::<HashSet<String>>(),
+            expected_knowledge_base
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_an_error_given_an_unknown_affinity() {
+        let demon_file_path = PathBuf::from("./test_files/test_valid_demon.ttl");
+        let affinity_file_path = PathBuf::from("./test_files/test_invalid_affinity.ttl");
+
+        let resp = Dataset::load(&[&demon_file_path, &affinity_file_path])
+            .and_then(|dataset| query_affinities(&dataset));
+
+        assert!(resp.unwrap_err().is::<ErrorUnknownAffinity>());
+    }
+
+    #[test]
+    fn should_return_no_affinity_given_a_dataset_without_affinities() -> Result<(), Error> {
+        let demon_file_path = PathBuf::from("./test_files/test_valid_demon.ttl");
+
+        let affinities =
+            Dataset::load(&[&demon_file_path]).and_then(|dataset| query_affinities(&dataset))?;
+
+        assert!(affinities.is_empty());
+        Ok(())
+    }
+}
@@ -18,24 +18,170 @@
 
 
 use argh::FromArgs;
+use oxrdfio::RdfFormat;
+use smt_nocturne_prolog_kb_generator::dataset::rdf_format_from_name;
+use smt_nocturne_prolog_kb_generator::model::KagutsuchiPhase;
+use smt_nocturne_prolog_kb_generator::prolog::NameRepresentation;
 use std::path::PathBuf;
 
 #[derive(FromArgs)]
-/// Generate a prolog knowledge base to describe and fuse demon from the game Shin Megami Tensei 3 nocture from RDF turtle files.
+/// Generate a prolog knowledge base to describe and fuse demon from the game Shin Megami Tensei 3 nocture from RDF files.
 pub(crate) struct CliArg {
-    /// the path of a the demon turtle file
+    /// the path of a the demon RDF file
     #[argh(option, short = 'd')]
     pub demon_rdf_file_path: PathBuf,
 
-    /// the path of a the race turtle file
+    /// the path of a the race RDF file
     #[argh(option, short = 'r')]
     pub race_rdf_file_path: PathBuf,
 
-    /// the path of a the fusion rule turtle file
+    /// the path of a the fusion rule RDF file
     #[argh(option, short = 'f')]
     pub fusion_rule_rdf_file_path: PathBuf,
 
+    /// the path of a the special fusion recipe RDF file
+    #[argh(option, short = 's')]
+    pub special_fusion_rdf_file_path: Option<PathBuf>,
+
+    /// the path of a the skill RDF file
+    #[argh(option, short = 'k')]
+    pub skill_rdf_file_path: Option<PathBuf>,
+
+    /// the path of a the Magatama RDF file
+    #[argh(option, short = 'm')]
+    pub magatama_rdf_file_path: Option<PathBuf>,
+
+    /// the format of the demon RDF file as a file extension or a media type, detected from the file extension by default
+    #[argh(option, from_str_fn(parse_rdf_format))]
+    pub demon_rdf_format: Option<RdfFormat>,
+
+    /// the format of the race RDF file as a file extension or a media type, detected from the file extension by default
+    #[argh(option, from_str_fn(parse_rdf_format))]
+    pub race_rdf_format: Option<RdfFormat>,
+
+    /// the format of the fusion rule RDF file as a file extension or a media type, detected from the file extension by default
+    #[argh(option, from_str_fn(parse_rdf_format))]
+    pub fusion_rule_rdf_format: Option<RdfFormat>,
+
+    /// the format of the special fusion recipe RDF file as a file extension or a media type, detected from the file extension by default
+    #[argh(option, from_str_fn(parse_rdf_format))]
+    pub special_fusion_rdf_format: Option<RdfFormat>,
+
+    /// the format of the skill RDF file as a file extension or a media type, detected from the file extension by default
+    #[argh(option, from_str_fn(parse_rdf_format))]
+    pub skill_rdf_format: Option<RdfFormat>,
+
+    /// the format of the Magatama RDF file as a file extension or a media type, detected from the file extension by default
+    #[argh(option, from_str_fn(parse_rdf_format))]
+    pub magatama_rdf_format: Option<RdfFormat>,
+
+    #[argh(switch)]
+    /// merge the named graphs of the quad formats into the graph of their file
+    pub merge_named_graphs: bool,
+
+    /// the representation of the names of demons and races, either "atom" or "string"
+    #[argh(option, default = "NameRepresentation::Atom")]
+    pub name_representation: NameRepresentation,
+
+    /// the name of the generated Prolog module
+    #[argh(option, default = "String::from(\"demon\")")]
+    pub module_name: String,
+
+    #[argh(switch)]
+    /// add the result of the normal fusion of every pair of demons to the knowledge base
+    pub fusion_chart: bool,
+
+    #[argh(switch)]
+    /// add the pairs of demons producing each demon with the normal fusion to the knowledge base
+    pub fusion_recipes: bool,
+
     #[argh(switch)]
     /// print the license
-    pub license: bool
+    pub license: bool,
+
+    #[argh(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(FromArgs)]
+#[argh(subcommand)]
+pub(crate) enum Command {
+    Recipe(RecipeCommand),
+    Plan(PlanCommand),
+    Simulate(SimulateCommand),
+}
+
+#[derive(FromArgs)]
+/// List the pairs of demons producing a demon with the normal fusion instead of generating the knowledge base.
+#[argh(subcommand, name = "recipe")]
+pub(crate) struct RecipeCommand {
+    /// the name of the demon to produce
+    #[argh(positional)]
+    pub demon: String,
+
+    /// the maximum level of the player, excluding the demons above it
+    #[argh(option)]
+    pub max_level: Option<i64>,
+}
+
+fn parse_rdf_format(value: &str) -> Result<RdfFormat, String> {
+    rdf_format_from_name(value).ok_or_else(|| format!("unknown RDF format '{value}'"))
+}
+
+#[derive(FromArgs)]
+/// Find the shortest sequence of fusions and purchases to obtain a demon from a party instead of generating the knowledge base.
+#[argh(subcommand, name = "plan")]
+pub(crate) struct PlanCommand {
+    /// the name of the demon to obtain
+    #[argh(positional)]
+    pub demon: String,
+
+    /// the name of a demon of the starting party, can be repeated up to 12 times, optionally followed by ':' and the comma-separated skills it carries instead of the skills it learns at its base level when --skill is given
+    #[argh(option, short = 'p')]
+    pub party: Vec<String>,
+
+    /// the name of a demon registered in the compendium that can be bought, can be repeated, the Macca costs being unsupported
+    #[argh(option, short = 'c')]
+    pub compendium: Vec<String>,
+
+    /// the maximum number of fusions and purchases of the plan, required to buy demons
+    #[argh(option)]
+    pub max_steps: Option<usize>,
+
+    /// the phase of Kagutsuchi during which the fusions are performed, an integer from 0 to 8, 'new', 'half' or 'full'
+    #[argh(option)]
+    pub phase: Option<KagutsuchiPhase>,
+
+    /// the name of a skill the demon to obtain must carry, can be repeated, requiring the skill RDF file
+    #[argh(option)]
+    pub skill: Vec<String>,
+
+    #[argh(switch)]
+    /// print the fusions of the plan as the list of actions of the Prolog predicate shortest_plan/3 instead of text, failing when the plan buys a demon
+    pub prolog: bool,
+}
+
+#[derive(FromArgs)]
+/// Simulate the normal fusion of two demons with assumed fusion accidents and print the probability of each result instead of generating the knowledge base.
+#[argh(subcommand, name = "simulate")]
+pub(crate) struct SimulateCommand {
+    /// the name of the first demon to fuse
+    #[argh(positional)]
+    pub demon1: String,
+
+    /// the name of the second demon to fuse
+    #[argh(positional)]
+    pub demon2: String,
+
+    /// the seed of the random number generator, the same seed reproducing the same accidents
+    #[argh(option, default = "0")]
+    pub seed: u64,
+
+    /// the phase of Kagutsuchi during which the fusions are performed, an integer from 0 to 8, 'new', 'half' or 'full'
+    #[argh(option, default = "KagutsuchiPhase::FULL")]
+    pub phase: KagutsuchiPhase,
+
+    /// the number of simulated fusions
+    #[argh(option, default = "1")]
+    pub count: usize,
 }
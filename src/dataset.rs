@@ -0,0 +1,329 @@
+// smt-nocturne-prolog-kb-generator: A generates of a Prolog knowledge base
+// to describe demons, their fusion, and fusion mechanics for the game 
+// Shin Megami Tensei III: Nocturne.
+// Copyright (C) 2025  Bryan-Elliott Tam
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::error::*;
+use anyhow::Error;
+use oxigraph::model::*;
+use oxigraph::sparql::{Query, QueryResults};
+use oxigraph::store::Store;
+use oxrdfio::{RdfFormat, RdfParseError, RdfParser};
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::path::{Path, PathBuf};
+
+/// A RDF file to load into a dataset.
+#[derive(Debug, Clone, Copy)]
+pub struct RdfFile<'a> {
+    pub path: &'a Path,
+    /// The serialization of the file, detected from its extension when it is not provided.
+    pub format: Option<RdfFormat>,
+}
+
+impl<'a> RdfFile<'a> {
+    pub fn new(path: &'a Path, format: Option<RdfFormat>) -> Self {
+        Self { path, format }
+    }
+
+    /// The serialization of the file.
+    pub fn format(&self) -> Result<RdfFormat, ErrorUnknownRdfFormat> {
+        self.format
+            .or_else(|| {
+                self.path
+                    .extension()
+                    .and_then(|extension| extension.to_str())
+                    .and_then(RdfFormat::from_extension)
+            })
+            .ok_or_else(|| ErrorUnknownRdfFormat {
+                path: self.path.to_path_buf(),
+            })
+    }
+}
+
+/// Find a RDF serialization from the name of its file extension or its media type.
+pub fn rdf_format_from_name(name: &str) -> Option<RdfFormat> {
+    RdfFormat::from_extension(name).or_else(|| RdfFormat::from_media_type(name))
+}
+
+/// How the named graphs of the files serialized in a quad format are loaded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NamedGraphs {
+    /// The named graphs of the files are kept.
+    #[default]
+    Preserve,
+    /// The named graphs of a file are merged into the graph of the file.
+    Merge,
+}
+
+/// A set of RDF files loaded once into a single store.
+///
+/// Each file is loaded into its own named graph to keep track of the provenance of the triples,
+/// while the queries are evaluated over the union of the graphs.
+pub struct Dataset {
+    store: Store,
+}
+
+impl Dataset {
+    /// Load RDF files into a new dataset, detecting their serialization from their extension.
+    pub fn load(files: &[&PathBuf]) -> Result<Self, Error> {
+        let files: Vec<RdfFile> = files
+            .iter()
+            .map(|file| RdfFile::new(file, None))
+            .collect();
+        Self::load_files(&files, NamedGraphs::default())
+    }
+
+    /// Load RDF files into a new dataset.
+    pub fn load_files(files: &[RdfFile], named_graphs: NamedGraphs) -> Result<Self, Error> {
+        let store = Store::new()?;
+
+        for file in files {
+            let reader = File::open(file.path)?;
+            if reader.metadata()?.is_dir() {
+                return Err(io::Error::from(io::ErrorKind::IsADirectory).into());
+            }
+            let reader = BufReader::new(reader);
+            let format = file.format()?;
+            let graph_name = Self::graph_name(file.path)?;
+            let parser = RdfParser::from_format(format)
+                .with_default_graph(graph_name.clone())
+                .rename_blank_nodes();
+
+            for result_quad in parser.for_reader(reader) {
+                let mut quad = result_quad.map_err(|error| match error {
+                    RdfParseError::Io(error) => Error::from(error),
+                    RdfParseError::Syntax(error) => Error::from(error),
+                })?;
+                if named_graphs == NamedGraphs::Merge {
+                    quad.graph_name = graph_name.clone().into();
+                }
+                store.insert(&quad)?;
+            }
+        }
+
+        Ok(Self { store })
+    }
+
+    /// The named graph containing the triples of a file.
+    pub fn graph_name(file: &Path) -> Result<NamedNode, Error> {
+        let path = file.canonicalize()?;
+        let mut iri = String::from("file://");
+        for byte in path.to_string_lossy().bytes() {
+            if byte.is_ascii_alphanumeric() || b"/-._~".contains(&byte) {
+                iri.push(byte as char);
+            } else {
+                iri.push_str(&format!("%{byte:02X}"));
+            }
+        }
+        Ok(NamedNode::new(iri)?)
+    }
+
+    /// Evaluate a SPARQL query over the union of the graphs of the dataset.
+    pub fn query(&self, query: &str) -> Result<QueryResults, Error> {
+        let mut query = Query::parse(query, None)?;
+        query.dataset_mut().set_default_graph_as_union();
+        Ok(self.store.query(query)?)
+    }
+
+    /// The number of quads in the named graph of a file.
+    pub fn len_of_graph(&self, file: &Path) -> Result<usize, Error> {
+        let graph_name = Self::graph_name(file)?;
+        Ok(self
+            .store
+            .quads_for_pattern(None, None, None, Some(graph_name.as_ref().into()))
+            .count())
+    }
+}
+
+#[cfg(test)]
+mod dataset_test {
+    use super::*;
+    use oxrdfio::RdfSyntaxError;
+
+    #[test]
+    fn should_return_an_error_given_a_non_existing_file() {
+        let file_path = PathBuf::from("./test_files/does_not_exist.ttl");
+
+        let resp = Dataset::load(&[&file_path]);
+
+        assert!(resp.is_err());
+        assert!(resp.err().unwrap().is::<io::Error>())
+    }
+
+    #[test]
+    fn should_return_an_error_given_a_directory() {
+        let file_path = PathBuf::from("./");
+
+        let resp = Dataset::load(&[&file_path]);
+
+        assert!(resp.is_err());
+        assert!(resp.err().unwrap().is::<io::Error>())
+    }
+
+    #[test]
+    fn should_return_an_error_given_an_invalid_file() {
+        let file_path = PathBuf::from("./test_files/test_invalid_demon.ttl");
+
+        let resp = Dataset::load(&[&file_path]);
+
+        assert!(resp.is_err());
+        assert!(resp.err().unwrap().is::<RdfSyntaxError>())
+    }
+
+    #[test]
+    fn should_return_an_error_given_a_file_with_an_unknown_format() {
+        let file_path = PathBuf::from("./test_files/test_valid_race.nt.data");
+
+        let resp = Dataset::load(&[&file_path]);
+
+        assert!(resp.is_err());
+        assert_eq!(
+            resp.err().unwrap().to_string(),
+            "unable to detect the RDF format of the file './test_files/test_valid_race.nt.data'"
+        );
+    }
+
+    #[test]
+    fn should_load_files_of_different_formats() -> Result<(), Error> {
+        let demon_file_path = PathBuf::from("./test_files/test_valid_demon.ttl");
+        let race_file_path = PathBuf::from("./test_files/test_valid_race.nt");
+        let rule_file_path = PathBuf::from("./test_files/test_valid_rule.nq");
+
+        let dataset = Dataset::load(&[&demon_file_path, &race_file_path, &rule_file_path])?;
+
+        assert_eq!(dataset.len_of_graph(&demon_file_path)?, 10);
+        assert_eq!(dataset.len_of_graph(&race_file_path)?, 12);
+        assert_eq!(dataset.len_of_graph(&rule_file_path)?, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn should_load_a_file_with_a_provided_format() -> Result<(), Error> {
+        let race_file_path = PathBuf::from("./test_files/test_valid_race.nt");
+
+        let dataset = Dataset::load_files(
+            &[RdfFile::new(&race_file_path, Some(RdfFormat::Turtle))],
+            NamedGraphs::Preserve,
+        )?;
+
+        assert_eq!(dataset.len_of_graph(&race_file_path)?, 12);
+        Ok(())
+    }
+
+    #[test]
+    fn should_merge_the_named_graphs_into_the_graph_of_the_file() -> Result<(), Error> {
+        let rule_file_path = PathBuf::from("./test_files/test_valid_rule.nq");
+
+        let dataset = Dataset::load_files(
+            &[RdfFile::new(&rule_file_path, None)],
+            NamedGraphs::Merge,
+        )?;
+
+        assert_eq!(dataset.len_of_graph(&rule_file_path)?, 6);
+        Ok(())
+    }
+
+    #[test]
+    fn should_load_each_file_in_its_own_graph() -> Result<(), Error> {
+        let demon_file_path = PathBuf::from("./test_files/test_valid_demon.ttl");
+        let race_file_path = PathBuf::from("./test_files/test_valid_race.ttl");
+
+        let dataset = Dataset::load(&[&demon_file_path, &race_file_path])?;
+
+        assert_eq!(dataset.len_of_graph(&demon_file_path)?, 10);
+        assert_eq!(dataset.len_of_graph(&race_file_path)?, 12);
+        Ok(())
+    }
+
+    #[test]
+    fn should_query_the_union_of_the_graphs() -> Result<(), Error> {
+        let demon_file_path = PathBuf::from("./test_files/test_valid_demon.ttl");
+        let race_file_path = PathBuf::from("./test_files/test_valid_race.ttl");
+
+        let dataset = Dataset::load(&[&demon_file_path, &race_file_path])?;
+
+        let query = "
+        PREFIX vocab: <https://constraintautomaton.github.io/smt-nocture-db-to-rdf/vocabulary.ttl#>
+        PREFIX schema: <https://schema.org/>
+        SELECT ?race WHERE { ?demon vocab:isOfRace ?raceIri . ?raceIri schema:name ?race . }";
+
+        if let QueryResults::Solutions(solutions) = dataset.query(query)? {
+            assert_eq!(solutions.count(), 2);
+        } else {
+            panic!("expected solutions");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn should_not_merge_blank_nodes_of_different_files() -> Result<(), Error> {
+        let rule_file_path = PathBuf::from("./test_files/test_valid_rule.ttl");
+        let same_rule_file_path = PathBuf::from("./test_files/../test_files/test_valid_rule.ttl");
+
+        let dataset = Dataset::load(&[&rule_file_path, &same_rule_file_path])?;
+
+        let query = "SELECT DISTINCT ?rule WHERE { GRAPH ?g { ?rule ?p ?o } }";
+        if let QueryResults::Solutions(solutions) = dataset.query(query)? {
+            assert_eq!(solutions.count(), 4);
+        } else {
+            panic!("expected solutions");
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod rdf_file_test {
+    use super::*;
+
+    #[test]
+    fn should_detect_the_format_from_the_extension() -> Result<(), Error> {
+        let formats = [
+            ("demon.ttl", RdfFormat::Turtle),
+            ("demon.nt", RdfFormat::NTriples),
+            ("demon.nq", RdfFormat::NQuads),
+            ("demon.rdf", RdfFormat::RdfXml),
+            ("demon.trig", RdfFormat::TriG),
+            ("demon.TTL", RdfFormat::Turtle),
+        ];
+        for (path, format) in formats {
+            assert_eq!(RdfFile::new(Path::new(path), None).format()?, format);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn should_prefer_the_provided_format() -> Result<(), Error> {
+        let file = RdfFile::new(Path::new("demon.ttl"), Some(RdfFormat::NTriples));
+
+        assert_eq!(file.format()?, RdfFormat::NTriples);
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_an_error_given_an_unknown_extension() {
+        assert!(RdfFile::new(Path::new("demon"), None).format().is_err());
+        assert!(RdfFile::new(Path::new("demon.json"), None).format().is_err());
+    }
+
+    #[test]
+    fn should_find_a_format_from_an_extension_or_a_media_type() {
+        assert_eq!(rdf_format_from_name("nq"), Some(RdfFormat::NQuads));
+        assert_eq!(rdf_format_from_name("application/trig"), Some(RdfFormat::TriG));
+        assert_eq!(rdf_format_from_name("json"), None);
+    }
+}
@@ -16,88 +16,177 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use crate::error::*;
+use crate::dataset::Dataset;
+use crate::model::*;
+use crate::prolog::*;
 use crate::util::*;
 use anyhow::Error;
 use oxigraph::sparql::QuerySolution;
-use std::path::PathBuf;
 
-/// create a prolog knowledge based containing information about Shin Megami Tensei Nocture demons based on RDF files.
+/// Query the Shin Megami Tensei Nocture demons from a dataset.
+pub fn query_demons(dataset: &Dataset) -> Result<Vec<Demon>, Error> {
+    query_model(dataset, GET_DEMON_QUERY, demon_from_solution)
+}
+
+/// Query the races of the Shin Megami Tensei Nocture demons from a dataset.
+pub fn query_races(dataset: &Dataset) -> Result<Vec<Race>, Error> {
+    query_model(dataset, GET_RACE_QUERY, race_from_solution)
+}
+
+/// create a prolog knowledge based containing information about Shin Megami Tensei Nocture demons.
 pub fn create_prolog_demon_knowledge_base(
-    demon_file_path: &PathBuf,
-    race_file_path: &PathBuf,
-) -> Result<Vec<String>, Error> {
-    let prolog_knowledge_base = create_prolog_knowledge_base(
-        vec![demon_file_path, race_file_path],
-        GET_DEMON_QUERY,
-        generate_a_prolog_fact,
-    )?;
-    Ok(prolog_knowledge_base)
+    demons: &[Demon],
+    name_representation: NameRepresentation,
+) -> Vec<PrologTerm> {
+    create_prolog_knowledge_base(demons, |demon| {
+        generate_a_prolog_fact(demon, name_representation)
+    })
 }
 
-fn generate_a_prolog_fact(solution_map: QuerySolution) -> Result<String, Error> {
-    let name = solution_map.get("name");
-    let race = solution_map.get("race");
-    let lv = solution_map.get("level");
-    if name.is_none() {
-        Err(ErrorProjectionVariableDoesNotExist {
-            variable: "name".to_string(),
-        }
-        .into())
-    } else if race.is_none() {
-        Err(ErrorProjectionVariableDoesNotExist {
-            variable: "race".to_string(),
-        }
-        .into())
-    } else if lv.is_none() {
-        Err(ErrorProjectionVariableDoesNotExist {
-            variable: "level".to_string(),
-        }
-        .into())
-    }  else {
-        let name = literal_string_to_string(name.unwrap(), "name")?.replace("'", "\\'");
-        let race = literal_string_to_string(race.unwrap(), "race")?;
-        let lv = literal_string_to_string(lv.unwrap(), "level")?;
-
-        Ok(format!(
-            "demon(\"{name}\", \"{race}\", {lv})."
-        ))
-    }
+fn demon_from_solution(solution_map: QuerySolution) -> Result<Demon, Error> {
+    let iri = solution_variable(&solution_map, "demon")?;
+    let name = solution_variable(&solution_map, "name")?;
+    let race = solution_variable(&solution_map, "race")?;
+    let lv = solution_variable(&solution_map, "level")?;
+    let special_fusion = solution_variable(&solution_map, "specialFusion")?;
+
+    Ok(Demon {
+        iri: named_node_to_string(iri, "demon")?,
+        name: literal_string_to_string(name, "name")?,
+        race: literal_string_to_string(race, "race")?,
+        base_level: literal_integer_to_i64(lv, "level")?,
+        special_fusion: literal_boolean_to_bool(special_fusion, "specialFusion")?,
+    })
+}
+
+fn race_from_solution(solution_map: QuerySolution) -> Result<Race, Error> {
+    let iri = solution_variable(&solution_map, "race")?;
+    let name = solution_variable(&solution_map, "name")?;
+
+    Ok(Race {
+        iri: named_node_to_string(iri, "race")?,
+        name: literal_string_to_string(name, "name")?,
+    })
+}
+
+pub(crate) fn generate_a_prolog_fact(demon: &Demon, name_representation: NameRepresentation) -> PrologTerm {
+    PrologTerm::compound(
+        "demon",
+        vec![
+            name_representation.term(&demon.name),
+            name_representation.term(&demon.race),
+            PrologTerm::Integer(demon.base_level),
+            PrologTerm::Boolean(demon.special_fusion),
+        ],
+    )
 }
 
-const GET_DEMON_QUERY: &'static str = "
+const GET_DEMON_QUERY: &str = "
 PREFIX vocab: <https://constraintautomaton.github.io/smt-nocture-db-to-rdf/vocabulary.ttl#>
 PREFIX schema: <https://schema.org/>
 
-SELECT ?name ?race ?level WHERE {
+SELECT ?demon ?name ?race ?level ?specialFusion WHERE {
     ?demon schema:name ?name;
         vocab:isOfRace ?raceIri;
         vocab:hasBasedLevel ?level.
+    OPTIONAL { ?demon vocab:cannotBeFusedWithBasicRules|vocab:specialFusion ?specialFusionFlag . }
+    BIND(COALESCE(?specialFusionFlag, false) AS ?specialFusion)
     
     ?raceIri schema:name ?race .
 }";
 
+const GET_RACE_QUERY: &str = "
+PREFIX vocab: <https://constraintautomaton.github.io/smt-nocture-db-to-rdf/vocabulary.ttl#>
+PREFIX schema: <https://schema.org/>
+
+SELECT ?race ?name WHERE {
+    ?race a vocab:Race;
+        schema:name ?name.
+}";
+
 #[cfg(test)]
-mod generate_a_prolog_fact_test {
+mod demon_from_solution_test {
     use super::*;
     use oxigraph::model::*;
 
+    #[test]
+    fn should_return_an_error_given_the_demon_is_not_a_named_node() -> Result<(), Error> {
+        let solution_map_demon_wrong: QuerySolution = QuerySolution::from((
+            vec![
+                Variable::new("demon")?,
+                Variable::new("name")?,
+                Variable::new("race")?,
+                Variable::new("level")?,
+                Variable::new("specialFusion")?,
+            ],
+            vec![
+                Term::Literal(Literal::from("a")).into(),
+                Term::Literal(Literal::from("a")).into(),
+                Term::Literal(Literal::from("b")).into(),
+                Term::Literal(Literal::from(1)).into(),
+                Term::Literal(Literal::from(false)).into(),
+            ],
+        ));
+
+        let res = demon_from_solution(solution_map_demon_wrong);
+        assert!(res.is_err());
+
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "the value of the variable 'demon' is not a named node".to_string()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_an_error_given_the_demon_is_not_in_the_solution_map() -> Result<(), Error> {
+        let solution_map: QuerySolution = QuerySolution::from((
+            vec![
+                Variable::new("name")?,
+                Variable::new("race")?,
+                Variable::new("level")?,
+                Variable::new("specialFusion")?,
+            ],
+            vec![
+                Term::Literal(Literal::from("a")).into(),
+                Term::Literal(Literal::from("b")).into(),
+                Term::Literal(Literal::from(1)).into(),
+                Term::Literal(Literal::from(false)).into(),
+            ],
+        ));
+
+        let res = demon_from_solution(solution_map);
+        assert!(res.is_err());
+
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "the variable 'demon' does not exist in the solution map".to_string()
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn should_return_an_error_given_the_name_is_not_a_literal() -> Result<(), Error> {
         let solution_map_name_wrong: QuerySolution = QuerySolution::from((
             vec![
+                Variable::new("demon")?,
                 Variable::new("name")?,
                 Variable::new("race")?,
                 Variable::new("level")?,
+                Variable::new("specialFusion")?,
             ],
             vec![
+                Term::NamedNode(NamedNode::new("http://example.com/a")?).into(),
                 Term::BlankNode(BlankNode::default()).into(),
                 Term::Literal(Literal::from("a")).into(),
                 Term::Literal(Literal::from("b")).into(),
+                Term::Literal(Literal::from(false)).into(),
             ],
         ));
 
-        let res = generate_a_prolog_fact(solution_map_name_wrong);
+        let res = demon_from_solution(solution_map_name_wrong);
         assert!(res.is_err());
 
         assert_eq!(
@@ -112,18 +201,22 @@ mod generate_a_prolog_fact_test {
     fn should_return_an_error_given_the_race_is_not_a_literal() -> Result<(), Error> {
         let solution_map_race_wrong: QuerySolution = QuerySolution::from((
             vec![
+                Variable::new("demon")?,
                 Variable::new("name")?,
                 Variable::new("race")?,
                 Variable::new("level")?,
+                Variable::new("specialFusion")?,
             ],
             vec![
+                Term::NamedNode(NamedNode::new("http://example.com/a")?).into(),
                 Term::Literal(Literal::from("a")).into(),
                 Term::BlankNode(BlankNode::default()).into(),
                 Term::Literal(Literal::from("b")).into(),
+                Term::Literal(Literal::from(false)).into(),
             ],
         ));
 
-        let res = generate_a_prolog_fact(solution_map_race_wrong);
+        let res = demon_from_solution(solution_map_race_wrong);
         assert!(res.is_err());
 
         assert_eq!(
@@ -138,23 +231,27 @@ mod generate_a_prolog_fact_test {
     fn should_return_an_error_given_the_level_is_not_a_literal() -> Result<(), Error> {
         let solution_map_level_wrong: QuerySolution = QuerySolution::from((
             vec![
+                Variable::new("demon")?,
                 Variable::new("name")?,
                 Variable::new("race")?,
                 Variable::new("level")?,
+                Variable::new("specialFusion")?,
             ],
             vec![
+                Term::NamedNode(NamedNode::new("http://example.com/a")?).into(),
                 Term::Literal(Literal::from("a")).into(),
                 Term::Literal(Literal::from("b")).into(),
                 Term::BlankNode(BlankNode::default()).into(),
+                Term::Literal(Literal::from(false)).into(),
             ],
         ));
 
-        let res = generate_a_prolog_fact(solution_map_level_wrong);
+        let res = demon_from_solution(solution_map_level_wrong);
         assert!(res.is_err());
 
         assert_eq!(
             res.unwrap_err().to_string(),
-            "the value of the variable 'level' is not a literal".to_string()
+            "the value of the variable 'level' is not an integer".to_string()
         );
 
         Ok(())
@@ -164,16 +261,20 @@ mod generate_a_prolog_fact_test {
     fn should_return_an_error_given_the_name_is_not_in_the_solution_map() -> Result<(), Error> {
         let solution_map: QuerySolution = QuerySolution::from((
             vec![
+                Variable::new("demon")?,
                 Variable::new("race")?,
-                Variable::new("level")?
+                Variable::new("level")?,
+                Variable::new("specialFusion")?,
             ],
             vec![
+                Term::NamedNode(NamedNode::new("http://example.com/a")?).into(),
                 Term::Literal(Literal::from("a")).into(),
                 Term::Literal(Literal::from("b")).into(),
+                Term::Literal(Literal::from(false)).into(),
             ],
         ));
 
-        let res = generate_a_prolog_fact(solution_map);
+        let res = demon_from_solution(solution_map);
         assert!(res.is_err());
 
         assert_eq!(
@@ -188,16 +289,19 @@ mod generate_a_prolog_fact_test {
     fn should_return_an_error_given_the_race_is_not_in_the_solution_map() -> Result<(), Error> {
         let solution_map: QuerySolution = QuerySolution::from((
             vec![
+                Variable::new("demon")?,
                 Variable::new("name")?,
                 Variable::new("level")?,
+                Variable::new("specialFusion")?,
             ],
             vec![
+                Term::NamedNode(NamedNode::new("http://example.com/a")?).into(),
                 Term::Literal(Literal::from("a")).into(),
                 Term::Literal(Literal::from("b")).into(),
             ],
         ));
 
-        let res = generate_a_prolog_fact(solution_map);
+        let res = demon_from_solution(solution_map);
         assert!(res.is_err());
 
         assert_eq!(
@@ -212,16 +316,20 @@ mod generate_a_prolog_fact_test {
     fn should_return_an_error_given_the_level_is_not_in_the_solution_map() -> Result<(), Error> {
         let solution_map: QuerySolution = QuerySolution::from((
             vec![
+                Variable::new("demon")?,
                 Variable::new("name")?,
                 Variable::new("race")?,
+                Variable::new("specialFusion")?,
             ],
             vec![
+                Term::NamedNode(NamedNode::new("http://example.com/a")?).into(),
                 Term::Literal(Literal::from("a")).into(),
                 Term::Literal(Literal::from("b")).into(),
+                Term::Literal(Literal::from(false)).into(),
             ],
         ));
 
-        let res = generate_a_prolog_fact(solution_map);
+        let res = demon_from_solution(solution_map);
         assert!(res.is_err());
 
         assert_eq!(
@@ -233,55 +341,219 @@ mod generate_a_prolog_fact_test {
     }
 
     #[test]
-    fn should_return_a_prolog_fact_given_a_valid_solution_map_with_a_boolean_special_fusion(
+    fn should_return_an_error_given_the_special_fusion_is_not_in_the_solution_map(
     ) -> Result<(), Error> {
         let solution_map: QuerySolution = QuerySolution::from((
             vec![
+                Variable::new("demon")?,
                 Variable::new("name")?,
                 Variable::new("race")?,
                 Variable::new("level")?,
             ],
             vec![
+                Term::NamedNode(NamedNode::new("http://example.com/a")?).into(),
                 Term::Literal(Literal::from("a")).into(),
                 Term::Literal(Literal::from("b")).into(),
                 Term::Literal(Literal::from("c")).into(),
             ],
         ));
 
-        let res = generate_a_prolog_fact(solution_map)?;
+        let res = demon_from_solution(solution_map);
+        assert!(res.is_err());
+
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "the variable 'specialFusion' does not exist in the solution map".to_string()
+        );
 
-        assert_eq!(res, "demon('a', 'b', c, false).".to_string());
         Ok(())
     }
 
     #[test]
-    fn should_return_a_prolog_fact_given_a_valid_solution_map_with_a_boolean_string_special_fusion(
+    fn should_return_an_error_given_the_special_fusion_is_not_a_boolean() -> Result<(), Error> {
+        let solution_map: QuerySolution = QuerySolution::from((
+            vec![
+                Variable::new("demon")?,
+                Variable::new("name")?,
+                Variable::new("race")?,
+                Variable::new("level")?,
+                Variable::new("specialFusion")?,
+            ],
+            vec![
+                Term::NamedNode(NamedNode::new("http://example.com/a")?).into(),
+                Term::Literal(Literal::from("a")).into(),
+                Term::Literal(Literal::from("b")).into(),
+                Term::Literal(Literal::from(1)).into(),
+                Term::Literal(Literal::from("d")).into(),
+            ],
+        ));
+
+        let res = demon_from_solution(solution_map);
+        assert!(res.is_err());
+
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "the value of the variable 'specialFusion' is not a boolean or a string boolean"
+                .to_string()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_a_demon_given_a_valid_solution_map_with_a_boolean_special_fusion(
     ) -> Result<(), Error> {
         let solution_map: QuerySolution = QuerySolution::from((
             vec![
+                Variable::new("demon")?,
                 Variable::new("name")?,
                 Variable::new("race")?,
                 Variable::new("level")?,
+                Variable::new("specialFusion")?,
             ],
             vec![
+                Term::NamedNode(NamedNode::new("http://example.com/a")?).into(),
+                Term::Literal(Literal::from("a")).into(),
+                Term::Literal(Literal::from("b")).into(),
+                Term::Literal(Literal::from(1)).into(),
+                Term::Literal(Literal::from(false)).into(),
+            ],
+        ));
+
+        let res = demon_from_solution(solution_map)?;
+
+        assert_eq!(
+            res,
+            Demon {
+                iri: "http://example.com/a".to_string(),
+                name: "a".to_string(),
+                race: "b".to_string(),
+                base_level: 1,
+                special_fusion: false,
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_a_demon_given_a_valid_solution_map_with_a_boolean_string_special_fusion(
+    ) -> Result<(), Error> {
+        let solution_map: QuerySolution = QuerySolution::from((
+            vec![
+                Variable::new("demon")?,
+                Variable::new("name")?,
+                Variable::new("race")?,
+                Variable::new("level")?,
+                Variable::new("specialFusion")?,
+            ],
+            vec![
+                Term::NamedNode(NamedNode::new("http://example.com/a")?).into(),
+                Term::Literal(Literal::from("a")).into(),
+                Term::Literal(Literal::from("b")).into(),
+                Term::Literal(Literal::from(1)).into(),
+                Term::Literal(Literal::from("true")).into(),
+            ],
+        ));
+
+        let res = demon_from_solution(solution_map)?;
+
+        assert_eq!(
+            res,
+            Demon {
+                iri: "http://example.com/a".to_string(),
+                name: "a".to_string(),
+                race: "b".to_string(),
+                base_level: 1,
+                special_fusion: true,
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_an_error_given_the_level_is_not_an_integer() -> Result<(), Error> {
+        let solution_map: QuerySolution = QuerySolution::from((
+            vec![
+                Variable::new("demon")?,
+                Variable::new("name")?,
+                Variable::new("race")?,
+                Variable::new("level")?,
+                Variable::new("specialFusion")?,
+            ],
+            vec![
+                Term::NamedNode(NamedNode::new("http://example.com/a")?).into(),
                 Term::Literal(Literal::from("a")).into(),
                 Term::Literal(Literal::from("b")).into(),
                 Term::Literal(Literal::from("c")).into(),
+                Term::Literal(Literal::from(false)).into(),
             ],
         ));
 
-        let res = generate_a_prolog_fact(solution_map)?;
+        let res = demon_from_solution(solution_map);
+        assert!(res.is_err());
+
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "the value of the variable 'level' is not an integer".to_string()
+        );
 
-        assert_eq!(res, "demon('a', 'b', c, true).".to_string());
         Ok(())
     }
 }
 
 #[cfg(test)]
-mod create_prolog_knowledge_base_test {
+mod generate_a_prolog_fact_test {
+    use super::*;
+
+    fn a_demon(name: &str) -> Demon {
+        Demon {
+            iri: "http://example.com/a".to_string(),
+            name: name.to_string(),
+            race: "Fairy".to_string(),
+            base_level: 1,
+            special_fusion: false,
+        }
+    }
+
+    #[test]
+    fn should_return_a_prolog_fact_with_atom_names() {
+        let res = generate_a_prolog_fact(&a_demon("Pixie"), NameRepresentation::Atom);
+
+        assert_eq!(res.to_fact(), "demon('Pixie', 'Fairy', 1, false).".to_string());
+    }
+
+    #[test]
+    fn should_return_a_prolog_fact_with_string_names() {
+        let res = generate_a_prolog_fact(&a_demon("Pixie"), NameRepresentation::String);
+
+        assert_eq!(
+            res.to_fact(),
+            "demon(\"Pixie\", \"Fairy\", 1, false).".to_string()
+        );
+    }
+
+    #[test]
+    fn should_return_a_prolog_fact_with_escaped_names() {
+        let res = generate_a_prolog_fact(&a_demon("Jack's Lantern"), NameRepresentation::Atom);
+        assert_eq!(
+            res.to_fact(),
+            "demon('Jack\\'s Lantern', 'Fairy', 1, false).".to_string()
+        );
+
+        let res = generate_a_prolog_fact(&a_demon("Jack's Lantern"), NameRepresentation::String);
+        assert_eq!(
+            res.to_fact(),
+            "demon(\"Jack's Lantern\", \"Fairy\", 1, false).".to_string()
+        );
+    }
+}
+
+#[cfg(test)]
+mod query_demons_test {
     use oxrdfio::RdfSyntaxError;
 
     use super::*;
+    use std::path::PathBuf;
     use std::collections::HashSet;
     use std::io;
 
@@ -290,7 +562,8 @@ mod create_prolog_knowledge_base_test {
         let demon_file_path = PathBuf::from("./");
         let race_file_path = PathBuf::from("./test_files/test_valid_race.ttl");
 
-        let resp = create_prolog_demon_knowledge_base(&demon_file_path, &race_file_path);
+        let resp = Dataset::load(&[&demon_file_path, &race_file_path])
+            .and_then(|dataset| query_demons(&dataset));
 
         assert!(resp.is_err());
         assert!(resp.unwrap_err().is::<io::Error>())
@@ -301,7 +574,8 @@ mod create_prolog_knowledge_base_test {
         let demon_file_path = PathBuf::from("./test_files/test_valid_demon.ttl");
         let race_file_path = PathBuf::from("./");
 
-        let resp = create_prolog_demon_knowledge_base(&demon_file_path, &race_file_path);
+        let resp = Dataset::load(&[&demon_file_path, &race_file_path])
+            .and_then(|dataset| query_demons(&dataset));
 
         assert!(resp.is_err());
         assert!(resp.unwrap_err().is::<io::Error>())
@@ -312,7 +586,8 @@ mod create_prolog_knowledge_base_test {
         let demon_file_path = PathBuf::from("./test_files/test_invalid_demon.ttl");
         let race_file_path = PathBuf::from("./test_files/test_valid_race.ttl");
 
-        let resp = create_prolog_demon_knowledge_base(&demon_file_path, &race_file_path);
+        let resp = Dataset::load(&[&demon_file_path, &race_file_path])
+            .and_then(|dataset| query_demons(&dataset));
 
         assert!(resp.is_err());
         assert!(resp.unwrap_err().is::<RdfSyntaxError>())
@@ -323,11 +598,68 @@ mod create_prolog_knowledge_base_test {
         let demon_file_path = PathBuf::from("./test_files/test_valid_demon.ttl");
         let race_file_path = PathBuf::from("./test_files/test_inconsistent_race.ttl");
 
-        let resp = create_prolog_demon_knowledge_base(&demon_file_path, &race_file_path);
+        let resp = Dataset::load(&[&demon_file_path, &race_file_path])
+            .and_then(|dataset| query_demons(&dataset));
 
         assert!(resp.is_err());
     }
 
+    #[test]
+    fn should_return_the_demons() -> Result<(), Error> {
+        let demon_file_path = PathBuf::from("./test_files/test_valid_demon.ttl");
+        let race_file_path = PathBuf::from("./test_files/test_valid_race.ttl");
+
+        let expected_demons: HashSet<Demon> = vec![
+            Demon {
+                iri: "https://constraintautomaton.github.io/smt-nocture-db-to-rdf/demon.ttl#Abaddon"
+                    .to_string(),
+                name: "Abaddon".to_string(),
+                race: "Tyrant".to_string(),
+                base_level: 69,
+                special_fusion: false,
+            },
+            Demon {
+                iri: "https://constraintautomaton.github.io/smt-nocture-db-to-rdf/demon.ttl#Aeros"
+                    .to_string(),
+                name: "Aeros".to_string(),
+                race: "Element".to_string(),
+                base_level: 11,
+                special_fusion: false,
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        let resp = Dataset::load(&[&demon_file_path, &race_file_path])
+            .and_then(|dataset| query_demons(&dataset))?;
+
+        assert_eq!(resp.into_iter().collect::<HashSet<Demon>>(), expected_demons);
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_a_demon_without_special_fusion_given_no_special_fusion_flag() -> Result<(), Error>
+    {
+        let demon_file_path = PathBuf::from("./test_files/test_demon_without_special_fusion.ttl");
+        let race_file_path = PathBuf::from("./test_files/test_valid_race.ttl");
+
+        let resp = Dataset::load(&[&demon_file_path, &race_file_path])
+            .and_then(|dataset| query_demons(&dataset))?;
+
+        assert_eq!(
+            resp,
+            vec![Demon {
+                iri: "https://constraintautomaton.github.io/smt-nocture-db-to-rdf/demon.ttl#Abaddon"
+                    .to_string(),
+                name: "Abaddon".to_string(),
+                race: "Tyrant".to_string(),
+                base_level: 69,
+                special_fusion: false,
+            }]
+        );
+        Ok(())
+    }
+
     #[test]
     fn should_return_a_prolog_knowledge_base() -> Result<(), Error> {
         let demon_file_path = PathBuf::from("./test_files/test_valid_demon.ttl");
@@ -340,12 +672,48 @@ mod create_prolog_knowledge_base_test {
         .into_iter()
         .collect();
 
-        let resp = create_prolog_demon_knowledge_base(&demon_file_path, &race_file_path)?;
+        let demons = Dataset::load(&[&demon_file_path, &race_file_path])
+            .and_then(|dataset| query_demons(&dataset))?;
+        let resp = create_prolog_demon_knowledge_base(&demons, NameRepresentation::Atom);
 
         assert_eq!(
-            resp.into_iter().collect::<HashSet<String>>(),
+            resp.iter().map(PrologTerm::to_fact).collect::<HashSet<String>>(),
             expected_knowledge_base
         );
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod query_races_test {
+    use super::*;
+    use std::path::PathBuf;
+    use oxrdfio::RdfSyntaxError;
+    use std::collections::HashSet;
+
+    #[test]
+    fn should_return_an_error_given_an_invalid_race_file() {
+        let race_file_path = PathBuf::from("./test_files/test_invalid_race.ttl");
+
+        let resp = Dataset::load(&[&race_file_path]).and_then(|dataset| query_races(&dataset));
+
+        assert!(resp.is_err());
+        assert!(resp.unwrap_err().is::<RdfSyntaxError>())
+    }
+
+    #[test]
+    fn should_return_the_races() -> Result<(), Error> {
+        let race_file_path = PathBuf::from("./test_files/test_valid_race.ttl");
+
+        let resp = Dataset::load(&[&race_file_path]).and_then(|dataset| query_races(&dataset))?;
+
+        assert_eq!(
+            resp.into_iter().map(|race| race.name).collect::<HashSet<String>>(),
+            ["Element", "Tyrant", "Beast", "Deity", "Fury", "Avatar"]
+                .into_iter()
+                .map(String::from)
+                .collect()
+        );
+        Ok(())
+    }
+}
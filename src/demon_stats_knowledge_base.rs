@@ -0,0 +1,383 @@
+// smt-nocturne-prolog-kb-generator: A generates of a Prolog knowledge base
+// to describe demons, their fusion, and fusion mechanics for the game 
+// Shin Megami Tensei III: Nocturne.
+// Copyright (C) 2025  Bryan-Elliott Tam
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::dataset::Dataset;
+use crate::error::{ErrorAmbiguousStat, ErrorIncompleteStatBlock, ErrorMissingStatBlock};
+use crate::model::*;
+use crate::prolog::*;
+use crate::util::*;
+use anyhow::Error;
+use oxigraph::sparql::QuerySolution;
+
+/// Query the base stats of the Shin Megami Tensei Nocture demons from a dataset.
+///
+/// Returns an error when a demon has only a part of its stats.
+pub fn query_demon_stats(dataset: &Dataset) -> Result<Vec<DemonStats>, Error> {
+    query_model(dataset, GET_DEMON_STATS_QUERY, demon_stats_from_solution)
+}
+
+/// Validate that every demon has exactly one stat block.
+///
+/// Returns an error when a demon has no stat block, or several ones because it has more than one value for a stat.
+pub fn validate_demon_stats(demons: &[Demon], demon_stats: &[DemonStats]) -> Result<(), Error> {
+    for demon in demons {
+        let mut stat_blocks = demon_stats.iter().filter(|stats| stats.demon == demon.name);
+        let Some(stat_block) = stat_blocks.next() else {
+            return Err(ErrorMissingStatBlock {
+                demon: demon.name.clone(),
+            }
+            .into());
+        };
+        if let Some(other_stat_block) = stat_blocks.next() {
+            return Err(ErrorAmbiguousStat {
+                demon: demon.name.clone(),
+                stat: differing_stat(stat_block, other_stat_block),
+            }
+            .into());
+        }
+    }
+    Ok(())
+}
+
+/// The first stat whose value differs between two stat blocks of a demon.
+fn differing_stat(stats1: &DemonStats, stats2: &DemonStats) -> &'static str {
+    [
+        ("strength", stats1.strength, stats2.strength),
+        ("magic", stats1.magic, stats2.magic),
+        ("vitality", stats1.vitality, stats2.vitality),
+        ("agility", stats1.agility, stats2.agility),
+        ("luck", stats1.luck, stats2.luck),
+    ]
+    .into_iter()
+    .find(|(_, value1, value2)| value1 != value2)
+    .map_or("strength", |(stat, _, _)| stat)
+}
+
+/// create a prolog knowledge based containing the base stats of Shin Megami Tensei Nocture demons.
+pub fn create_prolog_demon_stats_knowledge_base(
+    demon_stats: &[DemonStats],
+    name_representation: NameRepresentation,
+) -> Vec<PrologTerm> {
+    create_prolog_knowledge_base(demon_stats, |stats| {
+        generate_a_prolog_fact(stats, name_representation)
+    })
+}
+
+fn demon_stats_from_solution(solution_map: QuerySolution) -> Result<DemonStats, Error> {
+    let name = solution_variable(&solution_map, "name")?;
+    let demon = literal_string_to_string(name, "name")?;
+    let stat = |variable: &'static str| -> Result<i64, Error> {
+        let term = solution_map.get(variable).ok_or(ErrorIncompleteStatBlock {
+            demon: demon.clone(),
+            stat: variable,
+        })?;
+        Ok(literal_integer_to_i64(term, variable)?)
+    };
+
+    Ok(DemonStats {
+        strength: stat("strength")?,
+        magic: stat("magic")?,
+        vitality: stat("vitality")?,
+        agility: stat("agility")?,
+        luck: stat("luck")?,
+        demon,
+    })
+}
+
+fn generate_a_prolog_fact(
+    stats: &DemonStats,
+    name_representation: NameRepresentation,
+) -> PrologTerm {
+    PrologTerm::compound(
+        "demon_stats",
+        vec![
+            name_representation.term(&stats.demon),
+            PrologTerm::Integer(stats.strength),
+            PrologTerm::Integer(stats.magic),
+            PrologTerm::Integer(stats.vitality),
+            PrologTerm::Integer(stats.agility),
+            PrologTerm::Integer(stats.luck),
+        ],
+    )
+}
+
+const GET_DEMON_STATS_QUERY: &str = "
+PREFIX vocab: <https://constraintautomaton.github.io/smt-nocture-db-to-rdf/vocabulary.ttl#>
+PREFIX schema: <https://schema.org/>
+
+SELECT DISTINCT ?name ?strength ?magic ?vitality ?agility ?luck WHERE {
+    ?demon schema:name ?name ;
+        vocab:hasStrength|vocab:hasMagic|vocab:hasVitality|vocab:hasAgility|vocab:hasLuck [] .
+
+    OPTIONAL { ?demon vocab:hasStrength ?strength . }
+    OPTIONAL { ?demon vocab:hasMagic ?magic . }
+    OPTIONAL { ?demon vocab:hasVitality ?vitality . }
+    OPTIONAL { ?demon vocab:hasAgility ?agility . }
+    OPTIONAL { ?demon vocab:hasLuck ?luck . }
+}";
+
+#[cfg(test)]
+mod demon_stats_from_solution_test {
+    use super::*;
+    use oxigraph::model::*;
+
+    #[test]
+    fn should_return_an_error_given_an_incomplete_stat_block() -> Result<(), Error> {
+        let solution_map: QuerySolution = QuerySolution::from((
+            vec![
+                Variable::new("name")?,
+                Variable::new("strength")?,
+                Variable::new("magic")?,
+                Variable::new("vitality")?,
+                Variable::new("agility")?,
+            ],
+            vec![
+                Term::Literal(Literal::from("a")).into(),
+                Term::Literal(Literal::from(1)).into(),
+                Term::Literal(Literal::from(2)).into(),
+                Term::Literal(Literal::from(3)).into(),
+                Term::Literal(Literal::from(4)).into(),
+            ],
+        ));
+
+        let res = demon_stats_from_solution(solution_map);
+
+        assert_eq!(
+            res.err().map(|error| error.to_string()),
+            Some("the stat block of the demon 'a' has no value for the stat 'luck'".to_string())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_an_error_given_a_stat_is_not_an_integer() -> Result<(), Error> {
+        let solution_map: QuerySolution = QuerySolution::from((
+            vec![
+                Variable::new("name")?,
+                Variable::new("strength")?,
+                Variable::new("magic")?,
+                Variable::new("vitality")?,
+                Variable::new("agility")?,
+                Variable::new("luck")?,
+            ],
+            vec![
+                Term::Literal(Literal::from("a")).into(),
+                Term::Literal(Literal::from(1)).into(),
+                Term::Literal(Literal::from("b")).into(),
+                Term::Literal(Literal::from(3)).into(),
+                Term::Literal(Literal::from(4)).into(),
+                Term::Literal(Literal::from(5)).into(),
+            ],
+        ));
+
+        let res = demon_stats_from_solution(solution_map);
+
+        assert_eq!(
+            res.err().map(|error| error.to_string()),
+            Some("the value of the variable 'magic' is not an integer".to_string())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_the_stats_given_a_valid_solution_map() -> Result<(), Error> {
+        let solution_map: QuerySolution = QuerySolution::from((
+            vec![
+                Variable::new("name")?,
+                Variable::new("strength")?,
+                Variable::new("magic")?,
+                Variable::new("vitality")?,
+                Variable::new("agility")?,
+                Variable::new("luck")?,
+            ],
+            vec![
+                Term::Literal(Literal::from("a")).into(),
+                Term::Literal(Literal::from(1)).into(),
+                Term::Literal(Literal::from(2)).into(),
+                Term::Literal(Literal::from(3)).into(),
+                Term::Literal(Literal::from(4)).into(),
+                Term::Literal(Literal::from(5)).into(),
+            ],
+        ));
+
+        let res = demon_stats_from_solution(solution_map)?;
+
+        assert_eq!(
+            res,
+            DemonStats {
+                demon: "a".to_string(),
+                strength: 1,
+                magic: 2,
+                vitality: 3,
+                agility: 4,
+                luck: 5,
+            }
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod generate_a_prolog_fact_test {
+    use super::*;
+
+    #[test]
+    fn should_return_a_prolog_fact() {
+        let stats = DemonStats {
+            demon: "a".to_string(),
+            strength: 1,
+            magic: 2,
+            vitality: 3,
+            agility: 4,
+            luck: 5,
+        };
+
+        let res = generate_a_prolog_fact(&stats, NameRepresentation::Atom);
+        assert_eq!(
+            res.to_fact(),
+            "demon_stats('a', 1, 2, 3, 4, 5).".to_string()
+        );
+
+        let res = generate_a_prolog_fact(&stats, NameRepresentation::String);
+        assert_eq!(
+            res.to_fact(),
+            "demon_stats(\"a\", 1, 2, 3, 4, 5).".to_string()
+        );
+    }
+}
+
+#[cfg(test)]
+mod query_demon_stats_test {
+    use super::*;
+    use std::collections::HashSet;
+    use std::path::PathBuf;
+
+    #[test]
+    fn should_return_a_prolog_knowledge_base() -> Result<(), Error> {
+        let demon_file_path = PathBuf::from("./test_files/test_valid_demon.ttl");
+        let stats_file_path = PathBuf::from("./test_files/test_valid_demon_stats.ttl");
+        let expected_knowledge_base: HashSet<String> = vec![
+            "demon_stats('Abaddon', 18, 12, 16, 9, 8).".to_string(),
+            "demon_stats('Aeros', 5, 9, 6, 8, 5).".to_string(),
+        ]
+        .into_iter()
+        .collect();
+
+        let demon_stats = Dataset::load(&[&demon_file_path, &stats_file_path])
+            .and_then(|dataset| query_demon_stats(&dataset))?;
+        let resp = create_prolog_demon_stats_knowledge_base(&demon_stats, NameRepresentation::Atom);
+
+        assert_eq!(
+            resp.iter()
+                .map(PrologTerm::to_fact)
+                .collect::<HashSet<String>>(),
+            expected_knowledge_base
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_an_error_given_an_incomplete_stat_block() {
+        let demon_file_path = PathBuf::from("./test_files/test_valid_demon.ttl");
+        let stats_file_path = PathBuf::from("./test_files/test_incomplete_demon_stats.ttl");
+
+        let resp = Dataset::load(&[&demon_file_path, &stats_file_path])
+            .and_then(|dataset| query_demon_stats(&dataset));
+
+        assert!(resp.unwrap_err().is::<ErrorIncompleteStatBlock>());
+    }
+
+    #[test]
+    fn should_return_no_stats_given_a_dataset_without_stats() -> Result<(), Error> {
+        let demon_file_path = PathBuf::from("./test_files/test_valid_demon.ttl");
+
+        let demon_stats =
+            Dataset::load(&[&demon_file_path]).and_then(|dataset| query_demon_stats(&dataset))?;
+
+        assert!(demon_stats.is_empty());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod validate_demon_stats_test {
+    use super::*;
+
+    fn demon(name: &str) -> Demon {
+        Demon {
+            iri: format!("http://example.com/{name}"),
+            name: name.to_string(),
+            race: "Fairy".to_string(),
+            base_level: 1,
+            special_fusion: false,
+        }
+    }
+
+    fn stats(name: &str) -> DemonStats {
+        DemonStats {
+            demon: name.to_string(),
+            strength: 1,
+            magic: 1,
+            vitality: 1,
+            agility: 1,
+            luck: 1,
+        }
+    }
+
+    #[test]
+    fn should_return_an_error_given_a_dataset_without_stats() {
+        let resp = validate_demon_stats(&[demon("a")], &[]);
+
+        assert_eq!(
+            resp.err().map(|error| error.to_string()),
+            Some("the demon 'a' has no stat block".to_string())
+        );
+    }
+
+    #[test]
+    fn should_accept_no_stats_given_no_demons() {
+        assert!(validate_demon_stats(&[], &[]).is_ok());
+    }
+
+    #[test]
+    fn should_accept_a_stat_block_for_every_demon() {
+        assert!(validate_demon_stats(&[demon("a"), demon("b")], &[stats("b"), stats("a")]).is_ok());
+    }
+
+    #[test]
+    fn should_return_an_error_given_a_demon_without_stats() {
+        let resp = validate_demon_stats(&[demon("a"), demon("b")], &[stats("a")]);
+
+        assert_eq!(
+            resp.err().map(|error| error.to_string()),
+            Some("the demon 'b' has no stat block".to_string())
+        );
+    }
+
+    #[test]
+    fn should_return_an_error_given_a_demon_with_two_stat_blocks() {
+        let other_stats = DemonStats {
+            agility: 2,
+            ..stats("a")
+        };
+
+        let resp = validate_demon_stats(&[demon("a")], &[stats("a"), other_stats]);
+
+        assert!(resp.unwrap_err().is::<ErrorAmbiguousStat>());
+    }
+}
@@ -0,0 +1,259 @@
+// smt-nocturne-prolog-kb-generator: A generates of a Prolog knowledge base
+// to describe demons, their fusion, and fusion mechanics for the game 
+// Shin Megami Tensei III: Nocturne.
+// Copyright (C) 2025  Bryan-Elliott Tam
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::dataset::Dataset;
+use crate::model::*;
+use crate::prolog::*;
+use crate::util::*;
+use anyhow::Error;
+use oxigraph::sparql::QuerySolution;
+
+/// Query the Shin Megami Tensei Nocture rules producing an element demon from the fusion of two demons of the same race.
+pub fn query_element_fusion_rules(dataset: &Dataset) -> Result<Vec<ElementFusionRule>, Error> {
+    query_model(
+        dataset,
+        GET_ELEMENT_FUSION_RULE,
+        element_fusion_rule_from_solution,
+    )
+}
+
+/// Query the Shin Megami Tensei Nocture rules moving a demon inside its race when fused with an element demon.
+pub fn query_element_rank_shift_rules(
+    dataset: &Dataset,
+) -> Result<Vec<ElementRankShiftRule>, Error> {
+    query_model(
+        dataset,
+        GET_ELEMENT_RANK_SHIFT_RULE,
+        element_rank_shift_rule_from_solution,
+    )
+}
+
+/// create a prolog knowledge based containing the element demons produced by the fusion of two demons of the same race.
+pub fn create_prolog_element_fusion_knowledge_base(
+    element_fusion_rules: &[ElementFusionRule],
+    name_representation: NameRepresentation,
+) -> Vec<PrologTerm> {
+    create_prolog_knowledge_base(element_fusion_rules, |rule| {
+        PrologTerm::compound(
+            "fuse_element",
+            vec![
+                name_representation.term(&rule.race),
+                name_representation.term(&rule.element),
+            ],
+        )
+    })
+}
+
+/// create a prolog knowledge based containing the rank shifts of the demons fused with an element demon.
+pub fn create_prolog_element_rank_shift_knowledge_base(
+    element_rank_shift_rules: &[ElementRankShiftRule],
+    name_representation: NameRepresentation,
+) -> Vec<PrologTerm> {
+    create_prolog_knowledge_base(element_rank_shift_rules, |rule| {
+        PrologTerm::compound(
+            "element_rank_shift",
+            vec![
+                name_representation.term(&rule.race),
+                name_representation.term(&rule.element),
+                PrologTerm::Integer(rule.shift),
+            ],
+        )
+    })
+}
+
+fn element_fusion_rule_from_solution(
+    solution_map: QuerySolution,
+) -> Result<ElementFusionRule, Error> {
+    let race = solution_variable(&solution_map, "race")?;
+    let element = solution_variable(&solution_map, "element")?;
+
+    Ok(ElementFusionRule {
+        race: literal_string_to_string(race, "race")?,
+        element: literal_string_to_string(element, "element")?,
+    })
+}
+
+fn element_rank_shift_rule_from_solution(
+    solution_map: QuerySolution,
+) -> Result<ElementRankShiftRule, Error> {
+    let race = solution_variable(&solution_map, "race")?;
+    let element = solution_variable(&solution_map, "element")?;
+    let shift = solution_variable(&solution_map, "shift")?;
+
+    Ok(ElementRankShiftRule {
+        race: literal_string_to_string(race, "race")?,
+        element: literal_string_to_string(element, "element")?,
+        shift: literal_integer_to_i64(shift, "shift")?,
+    })
+}
+
+const GET_ELEMENT_FUSION_RULE: &str = "
+PREFIX vocab: <https://constraintautomaton.github.io/smt-nocture-db-to-rdf/vocabulary.ttl#>
+PREFIX schema: <https://schema.org/>
+
+SELECT ?race ?element WHERE {
+    [] vocab:withSameRace ?raceIri ;
+        vocab:fusionElementResult ?elementIri .
+
+    ?raceIri schema:name ?race .
+    ?elementIri schema:name ?element .
+}";
+
+const GET_ELEMENT_RANK_SHIFT_RULE: &str = "
+PREFIX vocab: <https://constraintautomaton.github.io/smt-nocture-db-to-rdf/vocabulary.ttl#>
+PREFIX schema: <https://schema.org/>
+
+SELECT ?race ?element ?shift WHERE {
+    [] vocab:shiftedRace ?raceIri ;
+        vocab:withElement ?elementIri ;
+        vocab:rankShift ?shift .
+
+    ?raceIri schema:name ?race .
+    ?elementIri schema:name ?element .
+}";
+
+#[cfg(test)]
+mod element_rank_shift_rule_from_solution_test {
+    use super::*;
+    use oxigraph::model::*;
+
+    #[test]
+    fn should_return_an_error_given_the_shift_is_not_an_integer() -> Result<(), Error> {
+        let solution_map: QuerySolution = QuerySolution::from((
+            vec![
+                Variable::new("race")?,
+                Variable::new("element")?,
+                Variable::new("shift")?,
+            ],
+            vec![
+                Term::Literal(Literal::from("a")).into(),
+                Term::Literal(Literal::from("b")).into(),
+                Term::Literal(Literal::from("up")).into(),
+            ],
+        ));
+
+        let res = element_rank_shift_rule_from_solution(solution_map);
+
+        assert_eq!(
+            res.err().map(|error| error.to_string()),
+            Some("the value of the variable 'shift' is not an integer".to_string())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_a_rule_given_a_valid_solution_map() -> Result<(), Error> {
+        let solution_map: QuerySolution = QuerySolution::from((
+            vec![
+                Variable::new("race")?,
+                Variable::new("element")?,
+                Variable::new("shift")?,
+            ],
+            vec![
+                Term::Literal(Literal::from("a")).into(),
+                Term::Literal(Literal::from("b")).into(),
+                Term::Literal(Literal::from(-1)).into(),
+            ],
+        ));
+
+        let res = element_rank_shift_rule_from_solution(solution_map)?;
+
+        assert_eq!(
+            res,
+            ElementRankShiftRule {
+                race: "a".to_string(),
+                element: "b".to_string(),
+                shift: -1,
+            }
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod element_fusion_rule_from_solution_test {
+    use super::*;
+    use oxigraph::model::*;
+
+    #[test]
+    fn should_return_an_error_given_the_element_is_not_in_the_solution_map() -> Result<(), Error> {
+        let solution_map: QuerySolution = QuerySolution::from((
+            vec![Variable::new("race")?],
+            vec![Term::Literal(Literal::from("a")).into()],
+        ));
+
+        let res = element_fusion_rule_from_solution(solution_map);
+
+        assert_eq!(
+            res.err().map(|error| error.to_string()),
+            Some("the variable 'element' does not exist in the solution map".to_string())
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod query_element_rules_test {
+    use super::*;
+    use std::collections::HashSet;
+    use std::path::PathBuf;
+
+    fn a_dataset() -> Result<Dataset, Error> {
+        let demon_file_path = PathBuf::from("./test_files/test_valid_demon.ttl");
+        let race_file_path = PathBuf::from("./test_files/test_valid_race.ttl");
+        let element_rule_file_path = PathBuf::from("./test_files/test_valid_element_rule.ttl");
+        Dataset::load(&[&demon_file_path, &race_file_path, &element_rule_file_path])
+    }
+
+    #[test]
+    fn should_return_a_prolog_element_fusion_knowledge_base() -> Result<(), Error> {
+        let rules = query_element_fusion_rules(&a_dataset()?)?;
+
+        let resp = create_prolog_element_fusion_knowledge_base(&rules, NameRepresentation::Atom);
+
+        assert_eq!(
+            resp.iter()
+                .map(PrologTerm::to_fact)
+                .collect::<Vec<String>>(),
+            vec!["fuse_element('Tyrant', 'Aeros').".to_string()]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_a_prolog_element_rank_shift_knowledge_base() -> Result<(), Error> {
+        let expected_knowledge_base: HashSet<String> = vec![
+            "element_rank_shift('Tyrant', 'Aeros', 1).".to_string(),
+            "element_rank_shift('Beast', 'Aeros', -1).".to_string(),
+        ]
+        .into_iter()
+        .collect();
+
+        let rules = query_element_rank_shift_rules(&a_dataset()?)?;
+        let resp =
+            create_prolog_element_rank_shift_knowledge_base(&rules, NameRepresentation::Atom);
+
+        assert_eq!(
+            resp.iter()
+                .map(PrologTerm::to_fact)
+                .collect::<HashSet<String>>(),
+            expected_knowledge_base
+        );
+        Ok(())
+    }
+}
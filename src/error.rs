@@ -16,55 +16,56 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use std;
-
 #[derive(Debug)]
-pub struct ErrorExpectSelectQuery;
+pub struct ErrorSolutionExpectedToBeBoolean {
+    pub variable: &'static str,
+}
 
-impl std::fmt::Display for ErrorExpectSelectQuery {
+impl std::fmt::Display for ErrorSolutionExpectedToBeBoolean {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "expect a SELECT SPARQL query"
+            "the value of the variable '{}' is not a boolean or a string boolean",
+            self.variable
         )
     }
 }
 
-impl std::error::Error for ErrorExpectSelectQuery {}
+impl std::error::Error for ErrorSolutionExpectedToBeBoolean {}
 
 #[derive(Debug)]
-pub struct ErrorSolutionExpectedToBeBoolean {
+pub struct ErrorSolutionExpectedToBeString {
     pub variable: &'static str,
 }
 
-impl std::fmt::Display for ErrorSolutionExpectedToBeBoolean {
+impl std::fmt::Display for ErrorSolutionExpectedToBeString {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "the value of the variable '{}' is not a boolean or a string boolean",
+            "the value of the variable '{}' is not a literal",
             self.variable
         )
     }
 }
 
-impl std::error::Error for ErrorSolutionExpectedToBeBoolean {}
+impl std::error::Error for ErrorSolutionExpectedToBeString {}
 
 #[derive(Debug)]
-pub struct ErrorSolutionExpectedToBeString {
+pub struct ErrorSolutionExpectedToBeNamedNode {
     pub variable: &'static str,
 }
 
-impl std::fmt::Display for ErrorSolutionExpectedToBeString {
+impl std::fmt::Display for ErrorSolutionExpectedToBeNamedNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "the value of the variable '{}' is not a literal",
+            "the value of the variable '{}' is not a named node",
             self.variable
         )
     }
 }
 
-impl std::error::Error for ErrorSolutionExpectedToBeString {}
+impl std::error::Error for ErrorSolutionExpectedToBeNamedNode {}
 
 #[derive(Debug)]
 pub struct ErrorProjectionVariableDoesNotExist {
@@ -91,3 +92,264 @@ impl std::fmt::Display for ErrorNotSelectQuery {
     }
 }
 impl std::error::Error for ErrorNotSelectQuery {}
+
+#[derive(Debug)]
+pub struct ErrorSolutionExpectedToBeInteger {
+    pub variable: &'static str,
+}
+
+impl std::fmt::Display for ErrorSolutionExpectedToBeInteger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "the value of the variable '{}' is not an integer",
+            self.variable
+        )
+    }
+}
+
+impl std::error::Error for ErrorSolutionExpectedToBeInteger {}
+
+#[derive(Debug)]
+pub struct ErrorUnknownNameRepresentation {
+    pub representation: String,
+}
+
+impl std::fmt::Display for ErrorUnknownNameRepresentation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown name representation '{}', expected 'atom' or 'string'",
+            self.representation
+        )
+    }
+}
+
+impl std::error::Error for ErrorUnknownNameRepresentation {}
+
+#[derive(Debug)]
+pub struct ErrorUnknownRdfFormat {
+    pub path: std::path::PathBuf,
+}
+
+impl std::fmt::Display for ErrorUnknownRdfFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unable to detect the RDF format of the file '{}'",
+            self.path.display()
+        )
+    }
+}
+
+impl std::error::Error for ErrorUnknownRdfFormat {}
+
+#[derive(Debug)]
+pub struct ErrorUnknownDemon {
+    pub name: String,
+}
+
+impl std::fmt::Display for ErrorUnknownDemon {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown demon '{}'", self.name)
+    }
+}
+
+impl std::error::Error for ErrorUnknownDemon {}
+
+#[derive(Debug)]
+pub struct ErrorUnknownSkill {
+    pub name: String,
+}
+
+impl std::fmt::Display for ErrorUnknownSkill {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown skill '{}'", self.name)
+    }
+}
+
+impl std::error::Error for ErrorUnknownSkill {}
+
+#[derive(Debug)]
+pub struct ErrorUnusedPartySkills {
+    pub member: String,
+}
+
+impl std::fmt::Display for ErrorUnusedPartySkills {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "the party demon '{}' carries skills but no skill is required with --skill",
+            self.member
+        )
+    }
+}
+
+impl std::error::Error for ErrorUnusedPartySkills {}
+
+#[derive(Debug)]
+pub struct ErrorPartyTooLarge {
+    pub size: usize,
+    pub max_size: usize,
+}
+
+impl std::fmt::Display for ErrorPartyTooLarge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "the party has {} demons but it can only have {} demons",
+            self.size, self.max_size
+        )
+    }
+}
+
+impl std::error::Error for ErrorPartyTooLarge {}
+
+#[derive(Debug)]
+pub struct ErrorUnboundedPlan {
+    pub compendium: usize,
+}
+
+impl std::fmt::Display for ErrorUnboundedPlan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "the plan can buy {} demons from the compendium but has no maximum number of steps",
+            self.compendium
+        )
+    }
+}
+
+impl std::error::Error for ErrorUnboundedPlan {}
+
+#[derive(Debug)]
+pub struct ErrorInconsistentIngredientCount {
+    pub result: String,
+    pub count: i64,
+    pub ingredients: usize,
+}
+
+impl std::fmt::Display for ErrorInconsistentIngredientCount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "the special fusion of '{}' expects {} ingredients but has {} ingredients",
+            self.result, self.count, self.ingredients
+        )
+    }
+}
+
+impl std::error::Error for ErrorInconsistentIngredientCount {}
+
+#[derive(Debug)]
+pub struct ErrorInvalidKagutsuchiPhase {
+    pub phase: String,
+}
+
+impl std::fmt::Display for ErrorInvalidKagutsuchiPhase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid phase of Kagutsuchi '{}', expected an integer from 0 to 8, 'new', 'half' or 'full'",
+            self.phase
+        )
+    }
+}
+
+impl std::error::Error for ErrorInvalidKagutsuchiPhase {}
+
+#[derive(Debug)]
+pub struct ErrorIncompleteStatBlock {
+    pub demon: String,
+    pub stat: &'static str,
+}
+
+impl std::fmt::Display for ErrorIncompleteStatBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "the stat block of the demon '{}' has no value for the stat '{}'",
+            self.demon, self.stat
+        )
+    }
+}
+
+impl std::error::Error for ErrorIncompleteStatBlock {}
+
+#[derive(Debug)]
+pub struct ErrorMissingStatBlock {
+    pub demon: String,
+}
+
+impl std::fmt::Display for ErrorMissingStatBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "the demon '{}' has no stat block", self.demon)
+    }
+}
+
+impl std::error::Error for ErrorMissingStatBlock {}
+
+#[derive(Debug)]
+pub struct ErrorAmbiguousStat {
+    pub demon: String,
+    pub stat: &'static str,
+}
+
+impl std::fmt::Display for ErrorAmbiguousStat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "the demon '{}' has more than one value for the stat '{}'",
+            self.demon, self.stat
+        )
+    }
+}
+
+impl std::error::Error for ErrorAmbiguousStat {}
+
+#[derive(Debug)]
+pub struct ErrorUnknownAffinity {
+    pub iri: String,
+}
+
+impl std::fmt::Display for ErrorUnknownAffinity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown affinity '{}'", self.iri)
+    }
+}
+
+impl std::error::Error for ErrorUnknownAffinity {}
+
+#[derive(Debug)]
+pub struct ErrorAmbiguousSkillCost {
+    pub skill: String,
+}
+
+impl std::fmt::Display for ErrorAmbiguousSkillCost {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "the skill '{}' has both an MP cost and an HP cost",
+            self.skill
+        )
+    }
+}
+
+impl std::error::Error for ErrorAmbiguousSkillCost {}
+
+#[derive(Debug)]
+pub struct ErrorUnsupportedPurchase {
+    pub demon: String,
+}
+
+impl std::fmt::Display for ErrorUnsupportedPurchase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "the plan buys the demon '{}', but the actions of the Prolog planner cannot describe a purchase",
+            self.demon
+        )
+    }
+}
+
+impl std::error::Error for ErrorUnsupportedPurchase {}
\ No newline at end of file
@@ -0,0 +1,217 @@
+// smt-nocturne-prolog-kb-generator: A generates of a Prolog knowledge base
+// to describe demons, their fusion, and fusion mechanics for the game 
+// Shin Megami Tensei III: Nocturne.
+// Copyright (C) 2025  Bryan-Elliott Tam
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::dataset::Dataset;
+use crate::model::*;
+use crate::prolog::*;
+use crate::util::*;
+use anyhow::Error;
+use oxigraph::sparql::QuerySolution;
+
+/// Query the evolutions of the Shin Megami Tensei Nocture demons from a dataset.
+pub fn query_evolutions(dataset: &Dataset) -> Result<Vec<Evolution>, Error> {
+    query_model(dataset, GET_EVOLUTION_QUERY, evolution_from_solution)
+}
+
+/// create a prolog knowledge based containing the evolutions of Shin Megami Tensei Nocture demons.
+pub fn create_prolog_evolution_knowledge_base(
+    evolutions: &[Evolution],
+    name_representation: NameRepresentation,
+) -> Vec<PrologTerm> {
+    create_prolog_knowledge_base(evolutions, |evolution| {
+        generate_a_prolog_fact(evolution, name_representation)
+    })
+}
+
+fn evolution_from_solution(solution_map: QuerySolution) -> Result<Evolution, Error> {
+    let from = solution_variable(&solution_map, "from")?;
+    let to = solution_variable(&solution_map, "to")?;
+    let level = solution_variable(&solution_map, "level")?;
+
+    Ok(Evolution {
+        from: literal_string_to_string(from, "from")?,
+        to: literal_string_to_string(to, "to")?,
+        level: literal_integer_to_i64(level, "level")?,
+    })
+}
+
+fn generate_a_prolog_fact(
+    evolution: &Evolution,
+    name_representation: NameRepresentation,
+) -> PrologTerm {
+    PrologTerm::compound(
+        "evolve",
+        vec![
+            name_representation.term(&evolution.from),
+            name_representation.term(&evolution.to),
+            PrologTerm::Integer(evolution.level),
+        ],
+    )
+}
+
+const GET_EVOLUTION_QUERY: &str = "
+PREFIX vocab: <https://constraintautomaton.github.io/smt-nocture-db-to-rdf/vocabulary.ttl#>
+PREFIX schema: <https://schema.org/>
+
+SELECT ?from ?to ?level WHERE {
+    [] vocab:evolvesFrom ?fromIri ;
+        vocab:evolvesTo ?toIri ;
+        vocab:evolutionLevel ?level .
+
+    ?fromIri schema:name ?from .
+    ?toIri schema:name ?to .
+}";
+
+#[cfg(test)]
+mod evolution_from_solution_test {
+    use super::*;
+    use oxigraph::model::*;
+
+    #[test]
+    fn should_return_an_error_given_the_level_is_not_an_integer() -> Result<(), Error> {
+        let solution_map: QuerySolution = QuerySolution::from((
+            vec![
+                Variable::new("from")?,
+                Variable::new("to")?,
+                Variable::new("level")?,
+            ],
+            vec![
+                Term::Literal(Literal::from("a")).into(),
+                Term::Literal(Literal::from("b")).into(),
+                Term::Literal(Literal::from("c")).into(),
+            ],
+        ));
+
+        let res = evolution_from_solution(solution_map);
+
+        assert_eq!(
+            res.err().map(|error| error.to_string()),
+            Some("the value of the variable 'level' is not an integer".to_string())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_an_error_given_the_to_is_not_in_the_solution_map() -> Result<(), Error> {
+        let solution_map: QuerySolution = QuerySolution::from((
+            vec![Variable::new("from")?, Variable::new("level")?],
+            vec![
+                Term::Literal(Literal::from("a")).into(),
+                Term::Literal(Literal::from(1)).into(),
+            ],
+        ));
+
+        let res = evolution_from_solution(solution_map);
+
+        assert_eq!(
+            res.err().map(|error| error.to_string()),
+            Some("the variable 'to' does not exist in the solution map".to_string())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_an_evolution_given_a_valid_solution_map() -> Result<(), Error> {
+        let solution_map: QuerySolution = QuerySolution::from((
+            vec![
+                Variable::new("from")?,
+                Variable::new("to")?,
+                Variable::new("level")?,
+            ],
+            vec![
+                Term::Literal(Literal::from("a")).into(),
+                Term::Literal(Literal::from("b")).into(),
+                Term::Literal(Literal::from(3)).into(),
+            ],
+        ));
+
+        let res = evolution_from_solution(solution_map)?;
+
+        assert_eq!(
+            res,
+            Evolution {
+                from: "a".to_string(),
+                to: "b".to_string(),
+                level: 3,
+            }
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod generate_a_prolog_fact_test {
+    use super::*;
+
+    #[test]
+    fn should_return_a_prolog_fact() {
+        let evolution = Evolution {
+            from: "a".to_string(),
+            to: "b".to_string(),
+            level: 3,
+        };
+
+        let res = generate_a_prolog_fact(&evolution, NameRepresentation::Atom);
+        assert_eq!(res.to_fact(), "evolve('a', 'b', 3).".to_string());
+
+        let res = generate_a_prolog_fact(&evolution, NameRepresentation::String);
+        assert_eq!(res.to_fact(), "evolve(\"a\", \"b\", 3).".to_string());
+    }
+}
+
+#[cfg(test)]
+mod query_evolutions_test {
+    use super::*;
+    use std::collections::HashSet;
+    use std::path::PathBuf;
+
+    #[test]
+    fn should_return_a_prolog_knowledge_base() -> Result<(), Error> {
+        let demon_file_path = PathBuf::from("./test_files/test_valid_demon.ttl");
+        let evolution_file_path = PathBuf::from("./test_files/test_valid_evolution.ttl");
+        let expected_knowledge_base: HashSet<String> = vec![
+            "evolve('Pixie', 'High Pixie', 21).".to_string(),
+            "evolve('Aeros', 'Abaddon', 40).".to_string(),
+        ]
+        .into_iter()
+        .collect();
+
+        let evolutions = Dataset::load(&[&demon_file_path, &evolution_file_path])
+            .and_then(|dataset| query_evolutions(&dataset))?;
+        let resp = create_prolog_evolution_knowledge_base(&evolutions, NameRepresentation::Atom);
+
+        assert_eq!(
+            resp.iter()
+                .map(PrologTerm::to_fact)
+                .collect::<HashSet<String>>(),
+            expected_knowledge_base
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_no_evolution_given_a_dataset_without_evolutions() -> Result<(), Error> {
+        let demon_file_path = PathBuf::from("./test_files/test_valid_demon.ttl");
+
+        let evolutions =
+            Dataset::load(&[&demon_file_path]).and_then(|dataset| query_evolutions(&dataset))?;
+
+        assert!(evolutions.is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,1117 @@
+// smt-nocturne-prolog-kb-generator: A generates of a Prolog knowledge base
+// to describe demons, their fusion, and fusion mechanics for the game 
+// Shin Megami Tensei III: Nocturne.
+// Copyright (C) 2025  Bryan-Elliott Tam
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::error::ErrorUnknownDemon;
+use crate::model::*;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+/// The normal fusion of two demons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NormalFusion<'a> {
+    pub demon1: &'a Demon,
+    pub demon2: &'a Demon,
+    pub result: &'a Demon,
+}
+
+/// The fusion of a demon with a Mitama, keeping the demon and boosting its stats.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MitamaFusion<'a> {
+    pub demon: &'a Demon,
+    pub mitama: &'a Demon,
+    /// The stats boosted by the Mitama.
+    pub boosts: Vec<&'a MitamaFusionRule>,
+}
+
+/// The normal fusion of two demons sacrificing a third demon, which transfers its experience and skills
+/// to the resulting demon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SacrificialFusion<'a> {
+    pub demon1: &'a Demon,
+    pub demon2: &'a Demon,
+    pub sacrifice: &'a Demon,
+    pub result: &'a Demon,
+}
+
+/// A kind of fusion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FusionKind {
+    Normal,
+    Mitama,
+    Sacrificial,
+}
+
+impl FusionKind {
+    /// Every kind of fusion.
+    pub const ALL: [FusionKind; 3] = [
+        FusionKind::Normal,
+        FusionKind::Mitama,
+        FusionKind::Sacrificial,
+    ];
+
+    /// The name of the kind of fusion in the knowledge base.
+    pub fn name(&self) -> &'static str {
+        match self {
+            FusionKind::Normal => "normal_fusion",
+            FusionKind::Mitama => "mitama_fusion",
+            FusionKind::Sacrificial => "sacrificial_fusion",
+        }
+    }
+
+    /// Whether a fusion of this kind can be performed during the phase of Kagutsuchi.
+    pub fn is_possible_at(&self, phase: KagutsuchiPhase) -> bool {
+        match self {
+            FusionKind::Normal | FusionKind::Mitama => true,
+            FusionKind::Sacrificial => phase == KagutsuchiPhase::FULL,
+        }
+    }
+}
+
+/// A fusion of demons, by kind of fusion.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fusion<'a> {
+    Normal(NormalFusion<'a>),
+    Mitama(MitamaFusion<'a>),
+    Sacrificial(SacrificialFusion<'a>),
+}
+
+impl<'a> Fusion<'a> {
+    /// The demon obtained with the fusion.
+    pub fn result(&self) -> &'a Demon {
+        match self {
+            Fusion::Normal(fusion) => fusion.result,
+            Fusion::Mitama(fusion) => fusion.demon,
+            Fusion::Sacrificial(fusion) => fusion.result,
+        }
+    }
+
+    pub fn kind(&self) -> FusionKind {
+        match self {
+            Fusion::Normal(_) => FusionKind::Normal,
+            Fusion::Mitama(_) => FusionKind::Mitama,
+            Fusion::Sacrificial(_) => FusionKind::Sacrificial,
+        }
+    }
+}
+
+/// The normal fusions of a pair of races producing the same demon.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FusionRecipes<'a> {
+    pub race1: &'a str,
+    pub race2: &'a str,
+    pub fusions: Vec<NormalFusion<'a>>,
+}
+
+/// The fusion of demons described in the model of the README.
+pub struct FusionEngine<'a> {
+    demons: &'a [Demon],
+    /// The resulting race of the fusion of two races, stored for both orders of the races.
+    race_fusion_rules: HashMap<(&'a str, &'a str), &'a str>,
+    /// The element demon resulting from the fusion of two demons of the same race.
+    element_fusion_rules: HashMap<&'a str, &'a Demon>,
+    /// The number of ranks a demon of a race moves when fused with an element demon.
+    element_rank_shifts: HashMap<(&'a str, &'a str), i64>,
+    /// The stats boosted by each Mitama.
+    mitama_boosts: HashMap<&'a str, Vec<&'a MitamaFusionRule>>,
+    /// The demons that can be fused with the normal fusion sorted by base level for each race.
+    normal_fusion_demons_by_race: HashMap<&'a str, Vec<&'a Demon>>,
+    special_fusion_recipes: &'a [SpecialFusionRecipe],
+    /// The pairs of race and skill category that the demons of the race can inherit.
+    inheritable_categories: HashSet<(&'a str, &'a str)>,
+    /// The names of the innate skills of each demon.
+    innate_skills: HashMap<&'a str, HashSet<&'a str>>,
+    /// The current phase of Kagutsuchi, every phase being considered when it is unknown.
+    phase: Option<KagutsuchiPhase>,
+}
+
+impl<'a> FusionEngine<'a> {
+    pub fn new(model: &'a Model) -> Self {
+        let mut race_fusion_rules = HashMap::new();
+        for rule in &model.race_fusion_rules {
+            race_fusion_rules.insert((rule.race1.as_str(), rule.race2.as_str()), rule.result.as_str());
+            race_fusion_rules.insert((rule.race2.as_str(), rule.race1.as_str()), rule.result.as_str());
+        }
+
+        let element_fusion_rules = model
+            .element_fusion_rules
+            .iter()
+            .filter_map(|rule| Some((rule.race.as_str(), model.demon(&rule.element)?)))
+            .collect();
+        let element_rank_shifts = model
+            .element_rank_shift_rules
+            .iter()
+            .map(|rule| ((rule.race.as_str(), rule.element.as_str()), rule.shift))
+            .collect();
+
+        let mut mitama_boosts: HashMap<&str, Vec<&MitamaFusionRule>> = HashMap::new();
+        for rule in &model.mitama_fusion_rules {
+            mitama_boosts.entry(rule.mitama.as_str()).or_default().push(rule);
+        }
+
+        let mut normal_fusion_demons_by_race: HashMap<&str, Vec<&Demon>> = HashMap::new();
+        for demon in model.demons.iter().filter(|demon| !demon.special_fusion) {
+            normal_fusion_demons_by_race
+                .entry(demon.race.as_str())
+                .or_default()
+                .push(demon);
+        }
+        for demons in normal_fusion_demons_by_race.values_mut() {
+            demons.sort_by(|a, b| a.base_level.cmp(&b.base_level).then(a.name.cmp(&b.name)));
+        }
+
+        let inheritable_categories = model
+            .inheritance_rules
+            .iter()
+            .map(|rule| (rule.race.as_str(), rule.category.as_str()))
+            .collect();
+        let mut innate_skills: HashMap<&str, HashSet<&str>> = HashMap::new();
+        for learned in &model.learned_skills {
+            if model
+                .demon(&learned.demon)
+                .is_some_and(|demon| learned.level <= demon.base_level)
+            {
+                innate_skills
+                    .entry(learned.demon.as_str())
+                    .or_default()
+                    .insert(learned.skill.as_str());
+            }
+        }
+
+        Self {
+            demons: &model.demons,
+            race_fusion_rules,
+            element_fusion_rules,
+            element_rank_shifts,
+            mitama_boosts,
+            normal_fusion_demons_by_race,
+            special_fusion_recipes: &model.special_fusion_recipes,
+            inheritable_categories,
+            innate_skills,
+            phase: None,
+        }
+    }
+
+    /// Restrict the fusions to the ones possible during the phase of Kagutsuchi.
+    pub fn with_phase(mut self, phase: KagutsuchiPhase) -> Self {
+        self.phase = Some(phase);
+        self
+    }
+
+    /// The current phase of Kagutsuchi.
+    pub fn phase(&self) -> Option<KagutsuchiPhase> {
+        self.phase
+    }
+
+    /// Whether a fusion of the kind can be performed during the current phase of Kagutsuchi.
+    pub fn allows(&self, kind: FusionKind) -> bool {
+        self.phase.is_none_or(|phase| kind.is_possible_at(phase))
+    }
+
+    /// The special fusion recipes producing the demon `target` that can be performed during the current phase of Kagutsuchi.
+    pub fn special_fusion_recipes(&self, target: &str) -> Vec<&'a SpecialFusionRecipe> {
+        self.special_fusion_recipes
+            .iter()
+            .filter(|recipe| recipe.result == target)
+            .filter(|recipe| {
+                recipe.kagutsuchi_phases.is_empty()
+                    || self
+                        .phase
+                        .is_none_or(|phase| recipe.kagutsuchi_phases.contains(&phase))
+            })
+            .collect()
+    }
+
+    /// The race mapping `f_R`, returning `None` for the invalid race `r_∅`.
+    pub fn fuse_race(&self, race1: &str, race2: &str) -> Option<&'a str> {
+        self.race_fusion_rules.get(&(race1, race2)).copied()
+    }
+
+    /// The fusion of two demons, which is a Mitama fusion when only one of the demons is a Mitama
+    /// and a normal fusion otherwise.
+    /// Returns `None` when the kind of fusion cannot be performed during the current phase of Kagutsuchi.
+    pub fn fuse(&self, demon1: &'a Demon, demon2: &'a Demon) -> Option<Fusion<'a>> {
+        self.mitama_fusion(demon1, demon2)
+            .or_else(|| self.mitama_fusion(demon2, demon1))
+            .map(Fusion::Mitama)
+            .or_else(|| {
+                self.normal_fusion(demon1, demon2).map(|result| {
+                    Fusion::Normal(NormalFusion {
+                        demon1,
+                        demon2,
+                        result,
+                    })
+                })
+            })
+            .filter(|fusion| self.allows(fusion.kind()))
+    }
+
+    /// The sacrificial fusion of two demons with the demon `sacrifice`, which produces the demon of their
+    /// normal fusion and is only possible during the full phase of Kagutsuchi.
+    /// Returns `None` when the normal fusion fails or when the current phase of Kagutsuchi is not full.
+    pub fn sacrificial_fusion(
+        &self,
+        demon1: &'a Demon,
+        demon2: &'a Demon,
+        sacrifice: &'a Demon,
+    ) -> Option<SacrificialFusion<'a>> {
+        if !self.allows(FusionKind::Sacrificial) {
+            return None;
+        }
+        let result = self.normal_fusion(demon1, demon2)?;
+        Some(SacrificialFusion {
+            demon1,
+            demon2,
+            sacrifice,
+            result,
+        })
+    }
+
+    /// The fusion of the demon `demon` with the Mitama `mitama`, returning `None` when `mitama` is not a Mitama
+    /// or when `demon` is also a Mitama.
+    pub fn mitama_fusion(&self, demon: &'a Demon, mitama: &'a Demon) -> Option<MitamaFusion<'a>> {
+        if self.is_mitama(demon) {
+            return None;
+        }
+        let boosts = self.mitama_boosts.get(mitama.name.as_str())?;
+        Some(MitamaFusion {
+            demon,
+            mitama,
+            boosts: boosts.clone(),
+        })
+    }
+
+    /// Whether the demon is a Mitama.
+    pub fn is_mitama(&self, demon: &Demon) -> bool {
+        self.mitama_boosts.contains_key(demon.name.as_str())
+    }
+
+    /// The normal fusion `f_d`, returning `None` for the invalid demon `d_∅`.
+    ///
+    /// The result is the demon of the race given by `f_R` that does not require a special fusion
+    /// and that has the lowest base level greater or equal to the average level of the two demons rounded down.
+    /// Demons of the same base level are ordered by name.
+    /// The fusion of a demon with a Mitama is not a normal fusion.
+    ///
+    /// The element rules are not applied, as `normal_fusion/3` of `planner.pl` does not describe them.
+    pub fn normal_fusion(&self, demon1: &Demon, demon2: &Demon) -> Option<&'a Demon> {
+        if self.is_mitama(demon1) != self.is_mitama(demon2) {
+            return None;
+        }
+
+        let race = self.fuse_race(&demon1.race, &demon2.race)?;
+        self.lowest_demon_above_average(race, demon1.base_level + demon2.base_level)
+    }
+
+    /// The fusion of two demons following the element rules, returning `None` when no rule applies.
+    ///
+    /// The fusion of a demon with an element demon follows the rank shift rule of its race when there is one,
+    /// and the fusion of two demons of the same race produces the element demon of their race when there is one.
+    /// The fusion also returns `None` when the shifted rank does not exist.
+    pub fn element_fusion(&self, demon1: &Demon, demon2: &Demon) -> Option<&'a Demon> {
+        for (demon, element) in [(demon1, demon2), (demon2, demon1)] {
+            if let Some(shift) = self
+                .element_rank_shifts
+                .get(&(demon.race.as_str(), element.name.as_str()))
+            {
+                return self.shift_rank(demon, *shift);
+            }
+        }
+        if demon1.race == demon2.race {
+            return self.element_fusion_rules.get(demon1.race.as_str()).copied();
+        }
+        None
+    }
+
+    /// The demon of the race that does not require a special fusion and that has the lowest base level
+    /// greater or equal to the average level, rounded down, of two demons whose levels sum to `level_sum`, as
+    /// `normal_fusion/3` of `planner.pl` does.
+    pub(crate) fn lowest_demon_above_average(&self, race: &str, level_sum: i64) -> Option<&'a Demon> {
+        self.normal_fusion_demons_by_race
+            .get(race)?
+            .iter()
+            .find(|demon| demon.base_level >= level_sum / 2)
+            .copied()
+    }
+
+    /// The races of the demons that can be fused with the normal fusion, sorted by name.
+    pub(crate) fn normal_fusion_races(&self) -> Vec<&'a str> {
+        let mut races: Vec<&'a str> = self
+            .normal_fusion_demons_by_race
+            .values()
+            .map(|demons| demons[0].race.as_str())
+            .collect();
+        races.sort();
+        races
+    }
+
+    /// The skills that the result of the normal fusion of two demons can inherit from the skills they carry,
+    /// sorted by name.
+    ///
+    /// A skill is inheritable when the race of the result can inherit its category and the result
+    /// does not already know it as an innate skill.
+    /// Returns `None` when the demons cannot be fused.
+    pub fn inheritable_skills(
+        &self,
+        demon1: &Demon,
+        skills1: &[&'a Skill],
+        demon2: &Demon,
+        skills2: &[&'a Skill],
+    ) -> Option<Vec<&'a Skill>> {
+        let result = self.normal_fusion(demon1, demon2)?;
+        let innate_skills = self.innate_skills.get(result.name.as_str());
+        let mut skills: Vec<&'a Skill> = skills1
+            .iter()
+            .chain(skills2)
+            .filter(|skill| {
+                self.inheritable_categories
+                    .contains(&(result.race.as_str(), skill.category.as_str()))
+            })
+            .filter(|skill| innate_skills.is_none_or(|innate| !innate.contains(skill.name.as_str())))
+            .copied()
+            .collect();
+        skills.sort_by(|a, b| a.name.cmp(&b.name));
+        skills.dedup_by(|a, b| a.name == b.name);
+        Some(skills)
+    }
+
+    /// The demon `shift` ranks above the demon in its race, or below it when `shift` is negative.
+    ///
+    /// Returns `None` when there is no such demon or when the demon requires a special fusion.
+    pub(crate) fn shift_rank(&self, demon: &Demon, shift: i64) -> Option<&'a Demon> {
+        let demons = self.normal_fusion_demons_by_race.get(demon.race.as_str())?;
+        let rank = demons
+            .iter()
+            .position(|other| other.name == demon.name)?;
+        let shifted_rank = usize::try_from(rank as i64 + shift).ok()?;
+        demons.get(shifted_rank).copied()
+    }
+
+    /// The successful normal fusions of every pair of distinct demons.
+    ///
+    /// The fusion being symmetric, each pair is only returned once with its demons ordered by name.
+    pub fn fusion_chart(&self) -> Vec<NormalFusion<'a>> {
+        let mut demons: Vec<&Demon> = self.demons.iter().collect();
+        demons.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut chart = Vec::new();
+        for (i, demon1) in demons.iter().enumerate() {
+            for demon2 in demons[i + 1..].iter() {
+                if let Some(result) = self.normal_fusion(demon1, demon2) {
+                    chart.push(NormalFusion {
+                        demon1,
+                        demon2,
+                        result,
+                    });
+                }
+            }
+        }
+        chart
+    }
+
+    /// The normal fusions producing the demon `target` grouped by the pair of races of the fused demons.
+    ///
+    /// When a maximum player level is given, the fusions involving a demon above that level are excluded.
+    pub fn fusion_recipes(
+        &self,
+        target: &str,
+        max_level: Option<i64>,
+    ) -> Result<Vec<FusionRecipes<'a>>, ErrorUnknownDemon> {
+        if !self.demons.iter().any(|demon| demon.name == target) {
+            return Err(ErrorUnknownDemon {
+                name: target.to_string(),
+            });
+        }
+        let within_level = |demon: &Demon| max_level.is_none_or(|level| demon.base_level <= level);
+
+        let mut recipes: BTreeMap<(&'a str, &'a str), Vec<NormalFusion<'a>>> = BTreeMap::new();
+        for fusion in self.fusion_chart() {
+            if fusion.result.name != target
+                || ![fusion.demon1, fusion.demon2, fusion.result]
+                    .into_iter()
+                    .all(within_level)
+            {
+                continue;
+            }
+            let race1 = fusion.demon1.race.as_str();
+            let race2 = fusion.demon2.race.as_str();
+            let races = if race1 <= race2 {
+                (race1, race2)
+            } else {
+                (race2, race1)
+            };
+            recipes.entry(races).or_default().push(fusion);
+        }
+
+        Ok(recipes
+            .into_iter()
+            .map(|((race1, race2), fusions)| FusionRecipes {
+                race1,
+                race2,
+                fusions,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod fusion_test_model {
+    use super::*;
+
+    pub(crate) fn demon(name: &str, race: &str, base_level: i64, special_fusion: bool) -> Demon {
+        Demon {
+            iri: format!("http://example.com/{name}"),
+            name: name.to_string(),
+            race: race.to_string(),
+            base_level,
+            special_fusion,
+        }
+    }
+
+    pub(crate) fn race_fusion_rule(race1: &str, race2: &str, result: &str) -> RaceFusionRule {
+        RaceFusionRule {
+            race1: race1.to_string(),
+            race2: race2.to_string(),
+            result: result.to_string(),
+        }
+    }
+
+    /// A small model inspired by the demons of the game.
+    pub(crate) fn a_model() -> Model {
+        Model {
+            demons: vec![
+                demon("Pixie", "Fairy", 2, false),
+                demon("High Pixie", "Fairy", 10, false),
+                demon("Kodama", "Jirae", 5, false),
+                demon("Hua Po", "Jirae", 8, false),
+                demon("Sudama", "Jirae", 13, false),
+                demon("Shikigami", "Brute", 4, false),
+                demon("Onkot", "Brute", 6, true),
+                demon("Ikusa", "Brute", 7, false),
+                demon("Kin-Ki", "Brute", 13, false),
+                demon("Oni", "Brute", 30, false),
+                demon("Uzume", "Megami", 16, false),
+                demon("Sarasvati", "Megami", 21, true),
+            ],
+            race_fusion_rules: vec![
+                race_fusion_rule("Fairy", "Jirae", "Brute"),
+                race_fusion_rule("Brute", "Fairy", "Jirae"),
+                race_fusion_rule("Brute", "Jirae", "Megami"),
+            ],
+            ..Model::default()
+        }
+    }
+
+    /// The small model with element demons and their rules.
+    pub(crate) fn a_model_with_elements() -> Model {
+        let mut model = a_model();
+        model.demons.push(demon("Erthys", "Element", 7, false));
+        model.demons.push(demon("Aeros", "Element", 11, false));
+        model.element_fusion_rules = vec![
+            ElementFusionRule {
+                race: "Fairy".to_string(),
+                element: "Aeros".to_string(),
+            },
+            ElementFusionRule {
+                race: "Jirae".to_string(),
+                element: "Erthys".to_string(),
+            },
+        ];
+        model.element_rank_shift_rules = vec![
+            ElementRankShiftRule {
+                race: "Brute".to_string(),
+                element: "Aeros".to_string(),
+                shift: 1,
+            },
+            ElementRankShiftRule {
+                race: "Brute".to_string(),
+                element: "Erthys".to_string(),
+                shift: -1,
+            },
+        ];
+        model
+    }
+
+    pub(crate) fn skill(name: &str, category: &str) -> Skill {
+        Skill {
+            name: name.to_string(),
+            element: category.to_string(),
+            category: category.to_string(),
+            cost: None,
+            power: None,
+            target: "single enemy".to_string(),
+        }
+    }
+
+    /// The small model with skills, the Brute demons inheriting the physical and fire skills.
+    pub(crate) fn a_model_with_skills() -> Model {
+        let mut model = a_model();
+        model.skills = vec![
+            skill("Agi", "Fire"),
+            skill("Bufu", "Ice"),
+            skill("Lunge", "Physical"),
+            skill("Zan", "Force"),
+        ];
+        model.learned_skills = vec![
+            LearnedSkill {
+                demon: "Pixie".to_string(),
+                skill: "Zan".to_string(),
+                level: 2,
+            },
+            LearnedSkill {
+                demon: "Kodama".to_string(),
+                skill: "Bufu".to_string(),
+                level: 5,
+            },
+            LearnedSkill {
+                demon: "Shikigami".to_string(),
+                skill: "Lunge".to_string(),
+                level: 4,
+            },
+            LearnedSkill {
+                demon: "Ikusa".to_string(),
+                skill: "Agi".to_string(),
+                level: 9,
+            },
+        ];
+        model.inheritance_rules = ["Physical", "Fire"]
+            .into_iter()
+            .map(|category| InheritanceRule {
+                race: "Brute".to_string(),
+                category: category.to_string(),
+            })
+            .collect();
+        model
+    }
+
+    /// The small model with Mitamas and their rules.
+    pub(crate) fn a_model_with_mitamas() -> Model {
+        let mut model = a_model();
+        model.demons.push(demon("Ara Mitama", "Mitama", 25, false));
+        model.demons.push(demon("Nigi Mitama", "Mitama", 29, false));
+        model.race_fusion_rules.push(race_fusion_rule("Fairy", "Mitama", "Jirae"));
+        model.mitama_fusion_rules = vec![
+            MitamaFusionRule {
+                mitama: "Ara Mitama".to_string(),
+                stat: "St".to_string(),
+                boost: 1,
+            },
+            MitamaFusionRule {
+                mitama: "Ara Mitama".to_string(),
+                stat: "Vi".to_string(),
+                boost: 1,
+            },
+            MitamaFusionRule {
+                mitama: "Nigi Mitama".to_string(),
+                stat: "Ma".to_string(),
+                boost: 2,
+            },
+        ];
+        model
+    }
+}
+
+#[cfg(test)]
+mod fusion_engine_test {
+    use super::fusion_test_model::*;
+    use super::*;
+
+    #[test]
+    fn should_fuse_races_in_both_orders() {
+        let model = a_model();
+        let engine = FusionEngine::new(&model);
+
+        assert_eq!(engine.fuse_race("Fairy", "Jirae"), Some("Brute"));
+        assert_eq!(engine.fuse_race("Jirae", "Fairy"), Some("Brute"));
+        assert_eq!(engine.fuse_race("Fairy", "Megami"), None);
+    }
+
+    #[test]
+    fn should_return_the_lowest_level_demon_above_the_average_level() {
+        let model = a_model();
+        let engine = FusionEngine::new(&model);
+        let pixie = model.demon("Pixie").unwrap();
+        let kodama = model.demon("Kodama").unwrap();
+
+        let result = engine.normal_fusion(pixie, kodama);
+
+        assert_eq!(result.map(|demon| demon.name.as_str()), Some("Shikigami"));
+    }
+
+    #[test]
+    fn should_round_down_the_average_level_of_an_odd_level_sum() {
+        let model = a_model();
+        let engine = FusionEngine::new(&model);
+        let ikusa = model.demon("Ikusa").unwrap();
+        let high_pixie = model.demon("High Pixie").unwrap();
+
+        let result = engine.normal_fusion(ikusa, high_pixie);
+
+        assert_eq!(result.map(|demon| demon.name.as_str()), Some("Hua Po"));
+    }
+
+    #[test]
+    fn should_keep_the_demons_at_the_average_level_of_an_even_level_sum() {
+        let model = a_model();
+        let engine = FusionEngine::new(&model);
+        let onkot = model.demon("Onkot").unwrap();
+        let high_pixie = model.demon("High Pixie").unwrap();
+
+        let result = engine.normal_fusion(onkot, high_pixie);
+
+        assert_eq!(result.map(|demon| demon.name.as_str()), Some("Hua Po"));
+    }
+
+    #[test]
+    fn should_skip_the_demons_requiring_a_special_fusion() {
+        let model = a_model();
+        let engine = FusionEngine::new(&model);
+        let pixie = model.demon("Pixie").unwrap();
+        let hua_po = model.demon("Hua Po").unwrap();
+
+        let result = engine.normal_fusion(pixie, hua_po);
+
+        assert_eq!(result.map(|demon| demon.name.as_str()), Some("Ikusa"));
+    }
+
+    #[test]
+    fn should_return_none_given_races_without_fusion_rule() {
+        let model = a_model();
+        let engine = FusionEngine::new(&model);
+        let pixie = model.demon("Pixie").unwrap();
+        let uzume = model.demon("Uzume").unwrap();
+
+        assert_eq!(engine.normal_fusion(pixie, uzume), None);
+    }
+
+    #[test]
+    fn should_return_each_successful_fusion_of_the_chart_once() {
+        let model = a_model();
+        let engine = FusionEngine::new(&model);
+
+        let chart: Vec<(&str, &str, &str)> = engine
+            .fusion_chart()
+            .into_iter()
+            .map(|fusion| {
+                (
+                    fusion.demon1.name.as_str(),
+                    fusion.demon2.name.as_str(),
+                    fusion.result.name.as_str(),
+                )
+            })
+            .collect();
+
+        assert!(chart.contains(&("Kodama", "Pixie", "Shikigami")));
+        assert!(!chart.contains(&("Pixie", "Kodama", "Shikigami")));
+        assert!(chart.contains(&("Hua Po", "Ikusa", "Uzume")));
+        assert!(chart.iter().all(|(demon1, demon2, _)| demon1 < demon2));
+        assert!(!chart
+            .iter()
+            .any(|(demon1, demon2, _)| (*demon1, *demon2) == ("High Pixie", "Oni")));
+        for (demon1, demon2, result) in &chart {
+            let fusion =
+                engine.normal_fusion(model.demon(demon1).unwrap(), model.demon(demon2).unwrap());
+            assert_eq!(fusion.map(|demon| demon.name.as_str()), Some(*result));
+        }
+    }
+
+    #[test]
+    fn should_return_none_given_no_demon_above_the_average_level() {
+        let model = a_model();
+        let engine = FusionEngine::new(&model);
+        let oni = model.demon("Oni").unwrap();
+        let high_pixie = model.demon("High Pixie").unwrap();
+
+        assert_eq!(engine.normal_fusion(oni, high_pixie), None);
+    }
+
+    #[test]
+    fn should_fuse_two_demons_of_the_same_race_into_an_element() {
+        let model = a_model_with_elements();
+        let engine = FusionEngine::new(&model);
+
+        let fusion = engine.element_fusion(
+            model.demon("Pixie").unwrap(),
+            model.demon("High Pixie").unwrap(),
+        );
+        assert_eq!(fusion, model.demon("Aeros"));
+
+        let fusion = engine.element_fusion(
+            model.demon("Kodama").unwrap(),
+            model.demon("Hua Po").unwrap(),
+        );
+        assert_eq!(fusion, model.demon("Erthys"));
+    }
+
+    #[test]
+    fn should_not_fuse_two_demons_of_a_race_without_element() {
+        let model = a_model_with_elements();
+        let engine = FusionEngine::new(&model);
+
+        let fusion = engine.element_fusion(
+            model.demon("Shikigami").unwrap(),
+            model.demon("Ikusa").unwrap(),
+        );
+
+        assert_eq!(fusion, None);
+    }
+
+    #[test]
+    fn should_shift_the_rank_of_a_demon_fused_with_an_element() {
+        let model = a_model_with_elements();
+        let engine = FusionEngine::new(&model);
+        let aeros = model.demon("Aeros").unwrap();
+        let erthys = model.demon("Erthys").unwrap();
+
+        assert_eq!(
+            engine.element_fusion(model.demon("Shikigami").unwrap(), aeros),
+            model.demon("Ikusa")
+        );
+        assert_eq!(
+            engine.element_fusion(aeros, model.demon("Shikigami").unwrap()),
+            model.demon("Ikusa")
+        );
+        assert_eq!(
+            engine.element_fusion(model.demon("Ikusa").unwrap(), erthys),
+            model.demon("Shikigami")
+        );
+    }
+
+    #[test]
+    fn should_not_shift_the_rank_of_a_demon_outside_of_its_race() {
+        let model = a_model_with_elements();
+        let engine = FusionEngine::new(&model);
+        let aeros = model.demon("Aeros").unwrap();
+        let erthys = model.demon("Erthys").unwrap();
+
+        assert_eq!(
+            engine.element_fusion(model.demon("Oni").unwrap(), aeros),
+            None
+        );
+        assert_eq!(
+            engine.element_fusion(model.demon("Shikigami").unwrap(), erthys),
+            None
+        );
+        assert_eq!(
+            engine.element_fusion(model.demon("Onkot").unwrap(), aeros),
+            None
+        );
+    }
+
+    #[test]
+    fn should_not_apply_the_element_rules_in_a_normal_fusion() {
+        let model = a_model_with_elements();
+        let engine = FusionEngine::new(&model);
+
+        assert_eq!(
+            engine.normal_fusion(
+                model.demon("Pixie").unwrap(),
+                model.demon("High Pixie").unwrap()
+            ),
+            None
+        );
+        assert_eq!(
+            engine.normal_fusion(
+                model.demon("Shikigami").unwrap(),
+                model.demon("Aeros").unwrap()
+            ),
+            None
+        );
+        assert_eq!(
+            engine.element_fusion(
+                model.demon("Pixie").unwrap(),
+                model.demon("Kodama").unwrap()
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn should_fuse_a_demon_with_a_mitama() {
+        let model = a_model_with_mitamas();
+        let engine = FusionEngine::new(&model);
+        let pixie = model.demon("Pixie").unwrap();
+        let ara_mitama = model.demon("Ara Mitama").unwrap();
+
+        let fusion = engine.fuse(ara_mitama, pixie);
+
+        assert_eq!(
+            fusion,
+            Some(Fusion::Mitama(MitamaFusion {
+                demon: pixie,
+                mitama: ara_mitama,
+                boosts: model.mitama_fusion_rules[..2].iter().collect(),
+            }))
+        );
+        assert_eq!(fusion.map(|fusion| fusion.result()), Some(pixie));
+        assert_eq!(engine.normal_fusion(pixie, ara_mitama), None);
+    }
+
+    #[test]
+    fn should_fuse_two_mitamas_with_the_normal_fusion() {
+        let model = a_model_with_mitamas();
+        let engine = FusionEngine::new(&model);
+        let ara_mitama = model.demon("Ara Mitama").unwrap();
+        let nigi_mitama = model.demon("Nigi Mitama").unwrap();
+
+        assert_eq!(engine.mitama_fusion(ara_mitama, nigi_mitama), None);
+        assert_eq!(engine.fuse(ara_mitama, nigi_mitama), None);
+    }
+
+    #[test]
+    fn should_fuse_two_demons_with_the_normal_fusion() {
+        let model = a_model_with_mitamas();
+        let engine = FusionEngine::new(&model);
+        let pixie = model.demon("Pixie").unwrap();
+        let kodama = model.demon("Kodama").unwrap();
+
+        assert_eq!(
+            engine.fuse(pixie, kodama),
+            Some(Fusion::Normal(NormalFusion {
+                demon1: pixie,
+                demon2: kodama,
+                result: model.demon("Shikigami").unwrap(),
+            }))
+        );
+    }
+
+    #[test]
+    fn should_return_the_special_fusion_recipes_of_the_phase() {
+        let mut model = a_model();
+        let recipe = SpecialFusionRecipe {
+            iri: "http://example.com/full".to_string(),
+            result: "Sarasvati".to_string(),
+            ingredients: vec!["Oni".to_string(), "Uzume".to_string()],
+            kagutsuchi_phases: vec![KagutsuchiPhase::NEW, KagutsuchiPhase::FULL],
+            prerequisites: vec![],
+        };
+        model.special_fusion_recipes = vec![
+            recipe.clone(),
+            SpecialFusionRecipe {
+                iri: "http://example.com/any".to_string(),
+                kagutsuchi_phases: vec![],
+                ..recipe
+            },
+        ];
+
+        let engine = FusionEngine::new(&model);
+        assert_eq!(engine.special_fusion_recipes("Sarasvati").len(), 2);
+        assert!(engine.special_fusion_recipes("Uzume").is_empty());
+
+        let engine = FusionEngine::new(&model).with_phase(KagutsuchiPhase::FULL);
+        assert_eq!(engine.special_fusion_recipes("Sarasvati").len(), 2);
+
+        let engine = FusionEngine::new(&model).with_phase(KagutsuchiPhase::NEW);
+        assert_eq!(engine.special_fusion_recipes("Sarasvati").len(), 2);
+
+        let engine = FusionEngine::new(&model).with_phase(KagutsuchiPhase::HALF);
+        assert_eq!(
+            engine.special_fusion_recipes("Sarasvati"),
+            vec![&model.special_fusion_recipes[1]]
+        );
+    }
+
+    #[test]
+    fn should_fuse_during_every_phase() {
+        let model = a_model_with_mitamas();
+        let pixie = model.demon("Pixie").unwrap();
+
+        for phase in KagutsuchiPhase::all() {
+            let engine = FusionEngine::new(&model).with_phase(phase);
+            assert_eq!(engine.phase(), Some(phase));
+            assert!(engine.fuse(pixie, model.demon("Kodama").unwrap()).is_some());
+            assert!(engine.fuse(pixie, model.demon("Ara Mitama").unwrap()).is_some());
+        }
+    }
+
+    #[test]
+    fn should_sacrifice_a_demon_during_the_full_phase() {
+        let model = a_model();
+        let pixie = model.demon("Pixie").unwrap();
+        let kodama = model.demon("Kodama").unwrap();
+        let oni = model.demon("Oni").unwrap();
+        let engine = FusionEngine::new(&model).with_phase(KagutsuchiPhase::FULL);
+
+        let fusion = engine.sacrificial_fusion(pixie, kodama, oni);
+
+        assert_eq!(
+            fusion,
+            Some(SacrificialFusion {
+                demon1: pixie,
+                demon2: kodama,
+                sacrifice: oni,
+                result: model.demon("Shikigami").unwrap(),
+            })
+        );
+        assert_eq!(
+            fusion.map(Fusion::Sacrificial).map(|fusion| fusion.kind()),
+            Some(FusionKind::Sacrificial)
+        );
+    }
+
+    #[test]
+    fn should_not_sacrifice_a_demon_outside_of_the_full_phase() {
+        let model = a_model();
+        let pixie = model.demon("Pixie").unwrap();
+        let kodama = model.demon("Kodama").unwrap();
+        let oni = model.demon("Oni").unwrap();
+
+        for phase in KagutsuchiPhase::all().filter(|phase| *phase != KagutsuchiPhase::FULL) {
+            let engine = FusionEngine::new(&model).with_phase(phase);
+            assert_eq!(engine.sacrificial_fusion(pixie, kodama, oni), None);
+        }
+        assert!(FusionEngine::new(&model)
+            .sacrificial_fusion(pixie, kodama, oni)
+            .is_some());
+    }
+
+    #[test]
+    fn should_not_sacrifice_a_demon_given_two_demons_without_normal_fusion() {
+        let model = a_model();
+        let engine = FusionEngine::new(&model);
+
+        assert_eq!(
+            engine.sacrificial_fusion(
+                model.demon("Pixie").unwrap(),
+                model.demon("Uzume").unwrap(),
+                model.demon("Kodama").unwrap()
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn should_return_the_skills_inheritable_by_the_race_of_the_result() {
+        let model = a_model_with_skills();
+        let engine = FusionEngine::new(&model);
+        let skill = |name| model.skill(name).unwrap();
+
+        let skills = engine.inheritable_skills(
+            model.demon("Pixie").unwrap(),
+            &[skill("Zan"), skill("Agi")],
+            model.demon("Kodama").unwrap(),
+            &[skill("Bufu"), skill("Agi")],
+        );
+
+        assert_eq!(skills, Some(vec![skill("Agi")]));
+    }
+
+    #[test]
+    fn should_not_inherit_an_innate_skill_of_the_result() {
+        let model = a_model_with_skills();
+        let engine = FusionEngine::new(&model);
+        let skill = |name| model.skill(name).unwrap();
+
+        let skills = engine.inheritable_skills(
+            model.demon("Pixie").unwrap(),
+            &[skill("Lunge")],
+            model.demon("Kodama").unwrap(),
+            &[],
+        );
+
+        assert_eq!(skills, Some(vec![]));
+    }
+
+    #[test]
+    fn should_not_inherit_skills_given_demons_that_cannot_be_fused() {
+        let model = a_model_with_skills();
+        let engine = FusionEngine::new(&model);
+
+        let skills = engine.inheritable_skills(
+            model.demon("Pixie").unwrap(),
+            &[model.skill("Agi").unwrap()],
+            model.demon("Uzume").unwrap(),
+            &[],
+        );
+
+        assert_eq!(skills, None);
+    }
+
+    #[test]
+    fn should_return_the_recipes_of_a_demon_grouped_by_race() -> Result<(), ErrorUnknownDemon> {
+        let model = a_model();
+        let engine = FusionEngine::new(&model);
+
+        let recipes = engine.fusion_recipes("Ikusa", None)?;
+
+        assert_eq!(
+            recipes,
+            vec![FusionRecipes {
+                race1: "Fairy",
+                race2: "Jirae",
+                fusions: vec![
+                    NormalFusion {
+                        demon1: model.demon("High Pixie").unwrap(),
+                        demon2: model.demon("Kodama").unwrap(),
+                        result: model.demon("Ikusa").unwrap(),
+                    },
+                    NormalFusion {
+                        demon1: model.demon("Hua Po").unwrap(),
+                        demon2: model.demon("Pixie").unwrap(),
+                        result: model.demon("Ikusa").unwrap(),
+                    },
+                    NormalFusion {
+                        demon1: model.demon("Pixie").unwrap(),
+                        demon2: model.demon("Sudama").unwrap(),
+                        result: model.demon("Ikusa").unwrap(),
+                    },
+                ],
+            }]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_only_the_recipes_of_the_target() -> Result<(), ErrorUnknownDemon> {
+        let model = a_model();
+        let engine = FusionEngine::new(&model);
+
+        let recipes = engine.fusion_recipes("Uzume", None)?;
+
+        assert!(!recipes.is_empty());
+        for recipe in &recipes {
+            assert_eq!((recipe.race1, recipe.race2), ("Brute", "Jirae"));
+            assert!(recipe
+                .fusions
+                .iter()
+                .all(|fusion| fusion.result.name == "Uzume"));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn should_exclude_the_recipes_above_the_max_level() -> Result<(), ErrorUnknownDemon> {
+        let model = a_model();
+        let engine = FusionEngine::new(&model);
+
+        assert_eq!(engine.fusion_recipes("Shikigami", Some(5))?.len(), 1);
+        assert_eq!(engine.fusion_recipes("Shikigami", Some(4))?, vec![]);
+        assert_eq!(engine.fusion_recipes("Uzume", Some(15))?, vec![]);
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_an_error_given_an_unknown_demon() {
+        let model = a_model();
+        let engine = FusionEngine::new(&model);
+
+        let resp = engine.fusion_recipes("Jack Frost", None);
+
+        assert!(resp.is_err());
+    }
+}
@@ -0,0 +1,151 @@
+// smt-nocturne-prolog-kb-generator: A generates of a Prolog knowledge base
+// to describe demons, their fusion, and fusion mechanics for the game 
+// Shin Megami Tensei III: Nocturne.
+// Copyright (C) 2025  Bryan-Elliott Tam
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::fusion::*;
+use crate::model::*;
+use crate::prolog::*;
+use crate::util::*;
+
+/// create a prolog knowledge based containing the result of the normal fusion of every pair of Shin Megami Tensei Nocture demons.
+pub fn create_prolog_fusion_chart_knowledge_base(
+    model: &Model,
+    name_representation: NameRepresentation,
+) -> Vec<PrologTerm> {
+    let engine = FusionEngine::new(model);
+    create_prolog_knowledge_base(&engine.fusion_chart(), |fusion| {
+        generate_a_prolog_fact(fusion, name_representation)
+    })
+}
+
+/// create a prolog knowledge based containing the pairs of Shin Megami Tensei Nocture demons producing each demon with the normal fusion.
+pub fn create_prolog_fusion_recipe_knowledge_base(
+    model: &Model,
+    name_representation: NameRepresentation,
+) -> Vec<PrologTerm> {
+    let engine = FusionEngine::new(model);
+    let mut chart = engine.fusion_chart();
+    chart.sort_by(|a, b| a.result.name.cmp(&b.result.name));
+    create_prolog_knowledge_base(&chart, |fusion| {
+        generate_a_prolog_recipe_fact(fusion, name_representation)
+    })
+}
+
+fn generate_a_prolog_fact(
+    fusion: &NormalFusion,
+    name_representation: NameRepresentation,
+) -> PrologTerm {
+    PrologTerm::compound(
+        "fusion_result",
+        vec![
+            name_representation.term(&fusion.demon1.name),
+            name_representation.term(&fusion.demon2.name),
+            name_representation.term(&fusion.result.name),
+        ],
+    )
+}
+
+fn generate_a_prolog_recipe_fact(
+    fusion: &NormalFusion,
+    name_representation: NameRepresentation,
+) -> PrologTerm {
+    PrologTerm::compound(
+        "fusion_recipe",
+        vec![
+            name_representation.term(&fusion.result.name),
+            name_representation.term(&fusion.demon1.name),
+            name_representation.term(&fusion.demon2.name),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod create_prolog_fusion_chart_knowledge_base_test {
+    use super::*;
+    use crate::fusion::fusion_test_model::*;
+
+    #[test]
+    fn should_return_a_prolog_knowledge_base() {
+        let model = Model {
+            demons: vec![
+                demon("Pixie", "Fairy", 2, false),
+                demon("Kodama", "Jirae", 5, false),
+                demon("Shikigami", "Brute", 4, false),
+            ],
+            race_fusion_rules: vec![race_fusion_rule("Fairy", "Jirae", "Brute")],
+            ..Model::default()
+        };
+
+        let resp = create_prolog_fusion_chart_knowledge_base(&model, NameRepresentation::Atom);
+
+        assert_eq!(
+            resp.iter()
+                .map(PrologTerm::to_fact)
+                .collect::<Vec<String>>(),
+            vec!["fusion_result('Kodama', 'Pixie', 'Shikigami').".to_string()]
+        );
+    }
+
+    #[test]
+    fn should_return_an_empty_knowledge_base_given_no_fusion_rule() {
+        let model = Model {
+            demons: vec![
+                demon("Pixie", "Fairy", 2, false),
+                demon("Kodama", "Jirae", 5, false),
+            ],
+            race_fusion_rules: vec![],
+            ..Model::default()
+        };
+
+        let resp = create_prolog_fusion_chart_knowledge_base(&model, NameRepresentation::Atom);
+
+        assert!(resp.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod create_prolog_fusion_recipe_knowledge_base_test {
+    use super::*;
+    use crate::fusion::fusion_test_model::*;
+
+    #[test]
+    fn should_return_a_prolog_knowledge_base_sorted_by_result() {
+        let model = Model {
+            demons: vec![
+                demon("Pixie", "Fairy", 2, false),
+                demon("Kodama", "Jirae", 5, false),
+                demon("Hua Po", "Jirae", 8, false),
+                demon("Shikigami", "Brute", 4, false),
+                demon("Ikusa", "Brute", 7, false),
+            ],
+            race_fusion_rules: vec![race_fusion_rule("Fairy", "Jirae", "Brute")],
+            ..Model::default()
+        };
+
+        let resp = create_prolog_fusion_recipe_knowledge_base(&model, NameRepresentation::Atom);
+
+        assert_eq!(
+            resp.iter()
+                .map(PrologTerm::to_fact)
+                .collect::<Vec<String>>(),
+            vec![
+                "fusion_recipe('Ikusa', 'Hua Po', 'Pixie').".to_string(),
+                "fusion_recipe('Shikigami', 'Kodama', 'Pixie').".to_string(),
+            ]
+        );
+    }
+}
@@ -16,55 +16,55 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use crate::error::*;
+use crate::dataset::Dataset;
+use crate::model::*;
+use crate::prolog::*;
 use crate::util::*;
 use anyhow::Error;
 use oxigraph::sparql::QuerySolution;
-use std::path::PathBuf;
 
-/// create a prolog knowledge based containing information about Shin Megami Tensei Nocture demon fusion rules based on RDF files.
+/// Query the Shin Megami Tensei Nocture race fusion rules from a dataset.
+pub fn query_race_fusion_rules(dataset: &Dataset) -> Result<Vec<RaceFusionRule>, Error> {
+    query_model(dataset, GET_ALL_FUSION_RULE, race_fusion_rule_from_solution)
+}
+
+/// create a prolog knowledge based containing information about Shin Megami Tensei Nocture demon fusion rules.
 pub fn create_prolog_fusion_rule_knowledge_base(
-    rule_rdf_file: &PathBuf,
-    race_file_path: &PathBuf,
-) -> Result<Vec<String>, Error> {
-    let prolog_knowledge_base = create_prolog_knowledge_base(
-        vec![rule_rdf_file, race_file_path],
-        GET_ALL_FUSION_RULE,
-        generate_a_prolog_fact,
-    )?;
-
-    Ok(prolog_knowledge_base)
+    race_fusion_rules: &[RaceFusionRule],
+    name_representation: NameRepresentation,
+) -> Vec<PrologTerm> {
+    create_prolog_knowledge_base(race_fusion_rules, |race_fusion_rule| {
+        generate_a_prolog_fact(race_fusion_rule, name_representation)
+    })
 }
 
-fn generate_a_prolog_fact(solution_map: QuerySolution) -> Result<String, Error> {
-    let race1 = solution_map.get("race1");
-    let race2 = solution_map.get("race2");
-    let race_r = solution_map.get("raceR");
-    if race1.is_none() {
-        Err(ErrorProjectionVariableDoesNotExist {
-            variable: "race1".to_string(),
-        }
-        .into())
-    } else if race2.is_none() {
-        Err(ErrorProjectionVariableDoesNotExist {
-            variable: "race2".to_string(),
-        }
-        .into())
-    } else if race_r.is_none() {
-        Err(ErrorProjectionVariableDoesNotExist {
-            variable: "raceR".to_string(),
-        }
-        .into())
-    } else {
-        let race1 = literal_string_to_string(race1.unwrap(), "race1")?;
-        let race2 = literal_string_to_string(race2.unwrap(), "race2")?;
-        let race_r = literal_string_to_string(race_r.unwrap(), "raceR")?;
-
-        Ok(format!("fuse_race(\"{race1}\", \"{race2}\", \"{race_r}\")."))
-    }
+fn race_fusion_rule_from_solution(solution_map: QuerySolution) -> Result<RaceFusionRule, Error> {
+    let race1 = solution_variable(&solution_map, "race1")?;
+    let race2 = solution_variable(&solution_map, "race2")?;
+    let race_r = solution_variable(&solution_map, "raceR")?;
+
+    Ok(RaceFusionRule {
+        race1: literal_string_to_string(race1, "race1")?,
+        race2: literal_string_to_string(race2, "race2")?,
+        result: literal_string_to_string(race_r, "raceR")?,
+    })
 }
 
-const GET_ALL_FUSION_RULE: &'static str = "
+fn generate_a_prolog_fact(
+    race_fusion_rule: &RaceFusionRule,
+    name_representation: NameRepresentation,
+) -> PrologTerm {
+    PrologTerm::compound(
+        "fuse_race",
+        vec![
+            name_representation.term(&race_fusion_rule.race1),
+            name_representation.term(&race_fusion_rule.race2),
+            name_representation.term(&race_fusion_rule.result),
+        ],
+    )
+}
+
+const GET_ALL_FUSION_RULE: &str = "
 PREFIX vocab: <https://constraintautomaton.github.io/smt-nocture-db-to-rdf/vocabulary.ttl#>
 PREFIX schema: <https://schema.org/>
 
@@ -79,7 +79,7 @@ SELECT ?race1 ?race2 ?raceR WHERE {
 }";
 
 #[cfg(test)]
-mod generate_a_prolog_fact_test {
+mod race_fusion_rule_from_solution_test {
     use super::*;
     use oxigraph::model::*;
 
@@ -98,7 +98,7 @@ mod generate_a_prolog_fact_test {
             ],
         ));
 
-        let res = generate_a_prolog_fact(solution_map_name_wrong);
+        let res = race_fusion_rule_from_solution(solution_map_name_wrong);
         assert!(res.is_err());
 
         assert_eq!(
@@ -124,7 +124,7 @@ mod generate_a_prolog_fact_test {
             ],
         ));
 
-        let res = generate_a_prolog_fact(solution_map_race_wrong);
+        let res = race_fusion_rule_from_solution(solution_map_race_wrong);
         assert!(res.is_err());
 
         assert_eq!(
@@ -150,7 +150,7 @@ mod generate_a_prolog_fact_test {
             ],
         ));
 
-        let res = generate_a_prolog_fact(solution_map_level_wrong);
+        let res = race_fusion_rule_from_solution(solution_map_level_wrong);
         assert!(res.is_err());
 
         assert_eq!(
@@ -174,7 +174,7 @@ mod generate_a_prolog_fact_test {
             ],
         ));
 
-        let res = generate_a_prolog_fact(solution_map);
+        let res = race_fusion_rule_from_solution(solution_map);
         assert!(res.is_err());
 
         assert_eq!(
@@ -198,7 +198,7 @@ mod generate_a_prolog_fact_test {
             ],
         ));
 
-        let res = generate_a_prolog_fact(solution_map);
+        let res = race_fusion_rule_from_solution(solution_map);
         assert!(res.is_err());
 
         assert_eq!(
@@ -222,7 +222,7 @@ mod generate_a_prolog_fact_test {
             ],
         ));
 
-        let res = generate_a_prolog_fact(solution_map);
+        let res = race_fusion_rule_from_solution(solution_map);
         assert!(res.is_err());
 
         assert_eq!(
@@ -235,7 +235,7 @@ mod generate_a_prolog_fact_test {
 
 
     #[test]
-    fn should_return_a_prolog_fact_given_a_valid_solution_map_with_a_boolean_string_cannot_be_fused_with_basic_rules(
+    fn should_return_a_race_fusion_rule_given_a_valid_solution_map(
     ) -> Result<(), Error> {
         let solution_map: QuerySolution = QuerySolution::from((
             vec![
@@ -250,18 +250,46 @@ mod generate_a_prolog_fact_test {
             ],
         ));
 
-        let res = generate_a_prolog_fact(solution_map)?;
+        let res = race_fusion_rule_from_solution(solution_map)?;
 
-        assert_eq!(res, "fuse_race('a', 'b', 'c').".to_string());
+        assert_eq!(
+            res,
+            RaceFusionRule {
+                race1: "a".to_string(),
+                race2: "b".to_string(),
+                result: "c".to_string(),
+            }
+        );
         Ok(())
     }
 }
 
 #[cfg(test)]
-mod create_prolog_knowledge_base_test {
+mod generate_a_prolog_fact_test {
+    use super::*;
+
+    #[test]
+    fn should_return_a_prolog_fact() {
+        let race_fusion_rule = RaceFusionRule {
+            race1: "a".to_string(),
+            race2: "b".to_string(),
+            result: "c".to_string(),
+        };
+
+        let res = generate_a_prolog_fact(&race_fusion_rule, NameRepresentation::Atom);
+        assert_eq!(res.to_fact(), "fuse_race('a', 'b', 'c').".to_string());
+
+        let res = generate_a_prolog_fact(&race_fusion_rule, NameRepresentation::String);
+        assert_eq!(res.to_fact(), "fuse_race(\"a\", \"b\", \"c\").".to_string());
+    }
+}
+
+#[cfg(test)]
+mod query_race_fusion_rules_test {
     use oxrdfio::RdfSyntaxError;
 
     use super::*;
+    use std::path::PathBuf;
     use std::collections::HashSet;
     use std::io;
 
@@ -270,7 +298,8 @@ mod create_prolog_knowledge_base_test {
         let rule_rdf_file = PathBuf::from("./");
         let race_file_path = PathBuf::from("./test_files/test_valid_race.ttl");
 
-        let resp = create_prolog_fusion_rule_knowledge_base(&rule_rdf_file, &race_file_path);
+        let resp = Dataset::load(&[&rule_rdf_file, &race_file_path])
+            .and_then(|dataset| query_race_fusion_rules(&dataset));
 
         assert!(resp.is_err());
         assert!(resp.unwrap_err().is::<io::Error>())
@@ -281,7 +310,8 @@ mod create_prolog_knowledge_base_test {
         let rule_rdf_file = PathBuf::from("./test_files/test_valid_rule.ttl");
         let race_file_path = PathBuf::from("./");
 
-        let resp = create_prolog_fusion_rule_knowledge_base(&rule_rdf_file, &race_file_path);
+        let resp = Dataset::load(&[&rule_rdf_file, &race_file_path])
+            .and_then(|dataset| query_race_fusion_rules(&dataset));
 
         assert!(resp.is_err());
         assert!(resp.unwrap_err().is::<io::Error>())
@@ -292,7 +322,8 @@ mod create_prolog_knowledge_base_test {
         let rule_rdf_file = PathBuf::from("./test_files/test_invalid_rule.ttl");
         let race_file_path = PathBuf::from("./test_files/test_valid_race.ttl");
 
-        let resp = create_prolog_fusion_rule_knowledge_base(&rule_rdf_file, &race_file_path);
+        let resp = Dataset::load(&[&rule_rdf_file, &race_file_path])
+            .and_then(|dataset| query_race_fusion_rules(&dataset));
 
         assert!(resp.is_err());
         assert!(resp.unwrap_err().is::<RdfSyntaxError>())
@@ -303,7 +334,8 @@ mod create_prolog_knowledge_base_test {
         let rule_rdf_file = PathBuf::from("./test_files/test_invalid_rule.ttl");
         let race_file_path = PathBuf::from("./test_files/test_inconsistent_race.ttl");
 
-        let resp = create_prolog_fusion_rule_knowledge_base(&rule_rdf_file, &race_file_path);
+        let resp = Dataset::load(&[&rule_rdf_file, &race_file_path])
+            .and_then(|dataset| query_race_fusion_rules(&dataset));
 
         assert!(resp.is_err());
     }
@@ -320,10 +352,13 @@ mod create_prolog_knowledge_base_test {
         .into_iter()
         .collect();
 
-        let resp = create_prolog_fusion_rule_knowledge_base(&rule_rdf_file, &race_file_path)?;
+        let race_fusion_rules = Dataset::load(&[&rule_rdf_file, &race_file_path])
+            .and_then(|dataset| query_race_fusion_rules(&dataset))?;
+        let resp =
+            create_prolog_fusion_rule_knowledge_base(&race_fusion_rules, NameRepresentation::Atom);
 
         assert_eq!(
-            resp.into_iter().collect::<HashSet<String>>(),
+            resp.iter().map(PrologTerm::to_fact).collect::<HashSet<String>>(),
             expected_knowledge_base
         );
         Ok(())
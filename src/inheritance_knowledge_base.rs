@@ -0,0 +1,157 @@
+// smt-nocturne-prolog-kb-generator: A generates of a Prolog knowledge base
+// to describe demons, their fusion, and fusion mechanics for the game 
+// Shin Megami Tensei III: Nocturne.
+// Copyright (C) 2025  Bryan-Elliott Tam
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::dataset::Dataset;
+use crate::model::*;
+use crate::prolog::*;
+use crate::util::*;
+use anyhow::Error;
+use oxigraph::sparql::QuerySolution;
+
+/// Query the categories of skills that the demons of each race of Shin Megami Tensei Nocture can inherit from a dataset.
+pub fn query_inheritance_rules(dataset: &Dataset) -> Result<Vec<InheritanceRule>, Error> {
+    query_model(
+        dataset,
+        GET_INHERITANCE_RULE,
+        inheritance_rule_from_solution,
+    )
+}
+
+/// create a prolog knowledge based containing the categories of skills that the demons of each race can inherit.
+pub fn create_prolog_inheritance_knowledge_base(
+    inheritance_rules: &[InheritanceRule],
+    name_representation: NameRepresentation,
+) -> Vec<PrologTerm> {
+    create_prolog_knowledge_base(inheritance_rules, |rule| {
+        generate_a_prolog_fact(rule, name_representation)
+    })
+}
+
+fn inheritance_rule_from_solution(solution_map: QuerySolution) -> Result<InheritanceRule, Error> {
+    let race = solution_variable(&solution_map, "race")?;
+    let category = solution_variable(&solution_map, "category")?;
+
+    Ok(InheritanceRule {
+        race: literal_string_to_string(race, "race")?,
+        category: literal_string_to_string(category, "category")?,
+    })
+}
+
+fn generate_a_prolog_fact(
+    rule: &InheritanceRule,
+    name_representation: NameRepresentation,
+) -> PrologTerm {
+    PrologTerm::compound(
+        "can_inherit",
+        vec![
+            name_representation.term(&rule.race),
+            name_representation.term(&rule.category),
+        ],
+    )
+}
+
+const GET_INHERITANCE_RULE: &str = "
+PREFIX vocab: <https://constraintautomaton.github.io/smt-nocture-db-to-rdf/vocabulary.ttl#>
+PREFIX schema: <https://schema.org/>
+
+SELECT ?race ?category WHERE {
+    [] vocab:inheritingRace ?raceIri ;
+        vocab:inheritableCategory ?category .
+
+    ?raceIri schema:name ?race .
+}";
+
+#[cfg(test)]
+mod inheritance_rule_from_solution_test {
+    use super::*;
+    use oxigraph::model::*;
+
+    #[test]
+    fn should_return_an_error_given_the_category_is_not_a_literal() -> Result<(), Error> {
+        let solution_map: QuerySolution = QuerySolution::from((
+            vec![Variable::new("race")?, Variable::new("category")?],
+            vec![
+                Term::Literal(Literal::from("a")).into(),
+                Term::BlankNode(BlankNode::default()).into(),
+            ],
+        ));
+
+        let res = inheritance_rule_from_solution(solution_map);
+
+        assert_eq!(
+            res.err().map(|error| error.to_string()),
+            Some("the value of the variable 'category' is not a literal".to_string())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_an_inheritance_rule_given_a_valid_solution_map() -> Result<(), Error> {
+        let solution_map: QuerySolution = QuerySolution::from((
+            vec![Variable::new("race")?, Variable::new("category")?],
+            vec![
+                Term::Literal(Literal::from("a")).into(),
+                Term::Literal(Literal::from("b")).into(),
+            ],
+        ));
+
+        let res = inheritance_rule_from_solution(solution_map)?;
+
+        assert_eq!(
+            res,
+            InheritanceRule {
+                race: "a".to_string(),
+                category: "b".to_string(),
+            }
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod query_inheritance_rules_test {
+    use super::*;
+    use std::collections::HashSet;
+    use std::path::PathBuf;
+
+    #[test]
+    fn should_return_a_prolog_knowledge_base() -> Result<(), Error> {
+        let race_file_path = PathBuf::from("./test_files/test_valid_race.ttl");
+        let rule_file_path = PathBuf::from("./test_files/test_valid_inheritance_rule.ttl");
+        let expected_knowledge_base: HashSet<String> = vec![
+            "can_inherit('Element', 'Force').".to_string(),
+            "can_inherit('Element', 'Passive').".to_string(),
+            "can_inherit('Tyrant', 'Physical').".to_string(),
+            "can_inherit('Tyrant', 'Support').".to_string(),
+        ]
+        .into_iter()
+        .collect();
+
+        let rules = Dataset::load(&[&race_file_path, &rule_file_path])
+            .and_then(|dataset| query_inheritance_rules(&dataset))?;
+        let resp = create_prolog_inheritance_knowledge_base(&rules, NameRepresentation::Atom);
+
+        assert_eq!(
+            resp.iter()
+                .map(PrologTerm::to_fact)
+                .collect::<HashSet<String>>(),
+            expected_knowledge_base
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+// smt-nocturne-prolog-kb-generator: A generates of a Prolog knowledge base
+// to describe demons, their fusion, and fusion mechanics for the game 
+// Shin Megami Tensei III: Nocturne.
+// Copyright (C) 2025  Bryan-Elliott Tam
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+pub mod affinity_knowledge_base;
+pub mod dataset;
+pub mod demon_knowledge_base;
+pub mod demon_stats_knowledge_base;
+pub mod element_fusion_knowledge_base;
+pub mod error;
+pub mod evolution_knowledge_base;
+pub mod fusion;
+pub mod fusion_chart_knowledge_base;
+pub mod fusion_rule_knowledge_base;
+pub mod inheritance_knowledge_base;
+pub mod magatama_knowledge_base;
+pub mod mitama_fusion_knowledge_base;
+pub mod model;
+pub mod phase_condition_knowledge_base;
+pub mod planner;
+pub mod prolog;
+pub mod recruitment_knowledge_base;
+pub mod simulation;
+pub mod skill_knowledge_base;
+pub mod special_fusion_knowledge_base;
+mod util;
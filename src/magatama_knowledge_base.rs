@@ -0,0 +1,375 @@
+// smt-nocturne-prolog-kb-generator: A generates of a Prolog knowledge base
+// to describe demons, their fusion, and fusion mechanics for the game 
+// Shin Megami Tensei III: Nocturne.
+// Copyright (C) 2025  Bryan-Elliott Tam
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::dataset::Dataset;
+use crate::model::*;
+use crate::prolog::*;
+use crate::util::*;
+use anyhow::Error;
+use oxigraph::sparql::QuerySolution;
+
+/// Query the Magatamas of the Demi-fiend from a dataset.
+///
+/// The stat bonuses missing from the dataset are zero.
+pub fn query_magatamas(dataset: &Dataset) -> Result<Vec<Magatama>, Error> {
+    query_model(dataset, GET_MAGATAMA_QUERY, magatama_from_solution)
+}
+
+/// Query the elemental affinities given by the Magatamas from a dataset.
+///
+/// Returns an error when an affinity is not one of the affinities of the vocabulary.
+pub fn query_magatama_affinities(dataset: &Dataset) -> Result<Vec<MagatamaAffinity>, Error> {
+    query_model(
+        dataset,
+        GET_MAGATAMA_AFFINITY_QUERY,
+        magatama_affinity_from_solution,
+    )
+}
+
+/// Query the skills taught by the Magatamas from a dataset.
+pub fn query_magatama_skills(dataset: &Dataset) -> Result<Vec<MagatamaSkill>, Error> {
+    query_model(
+        dataset,
+        GET_MAGATAMA_SKILL_QUERY,
+        magatama_skill_from_solution,
+    )
+}
+
+/// create a prolog knowledge based containing the Magatamas of the Demi-fiend.
+pub fn create_prolog_magatama_knowledge_base(
+    magatamas: &[Magatama],
+    name_representation: NameRepresentation,
+) -> Vec<PrologTerm> {
+    create_prolog_knowledge_base(magatamas, |magatama| {
+        generate_a_prolog_fact(magatama, name_representation)
+    })
+}
+
+/// create a prolog knowledge based containing the elemental affinities given by the Magatamas.
+pub fn create_prolog_magatama_affinity_knowledge_base(
+    affinities: &[MagatamaAffinity],
+    name_representation: NameRepresentation,
+) -> Vec<PrologTerm> {
+    create_prolog_knowledge_base(affinities, |affinity| {
+        PrologTerm::compound(
+            "magatama_affinity",
+            vec![
+                name_representation.term(&affinity.magatama),
+                name_representation.term(&affinity.element),
+                PrologTerm::Atom(affinity.affinity.name().to_string()),
+            ],
+        )
+    })
+}
+
+/// create a prolog knowledge based containing the skills taught by the Magatamas.
+pub fn create_prolog_magatama_skill_knowledge_base(
+    skills: &[MagatamaSkill],
+    name_representation: NameRepresentation,
+) -> Vec<PrologTerm> {
+    create_prolog_knowledge_base(skills, |skill| {
+        PrologTerm::compound(
+            "magatama_skill",
+            vec![
+                name_representation.term(&skill.magatama),
+                name_representation.term(&skill.skill),
+                PrologTerm::Integer(skill.level),
+            ],
+        )
+    })
+}
+
+fn magatama_from_solution(solution_map: QuerySolution) -> Result<Magatama, Error> {
+    let name = solution_variable(&solution_map, "name")?;
+    let bonus = |variable: &'static str| -> Result<i64, Error> {
+        Ok(solution_map
+            .get(variable)
+            .map(|term| literal_integer_to_i64(term, variable))
+            .transpose()?
+            .unwrap_or(0))
+    };
+
+    Ok(Magatama {
+        name: literal_string_to_string(name, "name")?,
+        strength: bonus("strength")?,
+        magic: bonus("magic")?,
+        vitality: bonus("vitality")?,
+        agility: bonus("agility")?,
+        luck: bonus("luck")?,
+        location: solution_map
+            .get("location")
+            .map(|location| literal_string_to_string(location, "location"))
+            .transpose()?,
+    })
+}
+
+fn magatama_affinity_from_solution(solution_map: QuerySolution) -> Result<MagatamaAffinity, Error> {
+    let name = solution_variable(&solution_map, "name")?;
+    let element = solution_variable(&solution_map, "element")?;
+    let kind = solution_variable(&solution_map, "kind")?;
+
+    Ok(MagatamaAffinity {
+        magatama: literal_string_to_string(name, "name")?,
+        element: literal_string_to_string(element, "element")?,
+        affinity: Affinity::from_iri(&named_node_to_string(kind, "kind")?)?,
+    })
+}
+
+fn magatama_skill_from_solution(solution_map: QuerySolution) -> Result<MagatamaSkill, Error> {
+    let magatama = solution_variable(&solution_map, "magatama")?;
+    let skill = solution_variable(&solution_map, "skill")?;
+    let level = solution_variable(&solution_map, "level")?;
+
+    Ok(MagatamaSkill {
+        magatama: literal_string_to_string(magatama, "magatama")?,
+        skill: literal_string_to_string(skill, "skill")?,
+        level: literal_integer_to_i64(level, "level")?,
+    })
+}
+
+fn generate_a_prolog_fact(
+    magatama: &Magatama,
+    name_representation: NameRepresentation,
+) -> PrologTerm {
+    PrologTerm::compound(
+        "magatama",
+        vec![
+            name_representation.term(&magatama.name),
+            PrologTerm::Integer(magatama.strength),
+            PrologTerm::Integer(magatama.magic),
+            PrologTerm::Integer(magatama.vitality),
+            PrologTerm::Integer(magatama.agility),
+            PrologTerm::Integer(magatama.luck),
+            magatama
+                .location
+                .as_ref()
+                .map_or(PrologTerm::Atom("none".to_string()), |location| {
+                    name_representation.term(location)
+                }),
+        ],
+    )
+}
+
+const GET_MAGATAMA_QUERY: &str = "
+PREFIX vocab: <https://constraintautomaton.github.io/smt-nocture-db-to-rdf/vocabulary.ttl#>
+PREFIX schema: <https://schema.org/>
+
+SELECT ?name ?strength ?magic ?vitality ?agility ?luck ?location WHERE {
+    ?magatama a vocab:Magatama ;
+        schema:name ?name .
+
+    OPTIONAL { ?magatama vocab:strengthBonus ?strength . }
+    OPTIONAL { ?magatama vocab:magicBonus ?magic . }
+    OPTIONAL { ?magatama vocab:vitalityBonus ?vitality . }
+    OPTIONAL { ?magatama vocab:agilityBonus ?agility . }
+    OPTIONAL { ?magatama vocab:luckBonus ?luck . }
+    OPTIONAL { ?magatama vocab:obtainedIn ?location . }
+}";
+
+const GET_MAGATAMA_AFFINITY_QUERY: &str = "
+PREFIX vocab: <https://constraintautomaton.github.io/smt-nocture-db-to-rdf/vocabulary.ttl#>
+PREFIX schema: <https://schema.org/>
+
+SELECT ?name ?element ?kind WHERE {
+    ?magatama a vocab:Magatama ;
+        schema:name ?name ;
+        vocab:magatamaAffinity ?affinity .
+
+    ?affinity vocab:affinityElement ?element ;
+        vocab:affinityKind ?kind .
+}";
+
+const GET_MAGATAMA_SKILL_QUERY: &str = "
+PREFIX vocab: <https://constraintautomaton.github.io/smt-nocture-db-to-rdf/vocabulary.ttl#>
+PREFIX schema: <https://schema.org/>
+
+SELECT ?magatama ?skill ?level WHERE {
+    [] vocab:taughtBy ?magatamaIri ;
+        vocab:learnedSkill ?skillIri ;
+        vocab:learnLevel ?level .
+
+    ?magatamaIri a vocab:Magatama ;
+        schema:name ?magatama .
+    ?skillIri schema:name ?skill .
+}";
+
+#[cfg(test)]
+mod magatama_from_solution_test {
+    use super::*;
+    use oxigraph::model::*;
+
+    #[test]
+    fn should_return_an_error_given_a_bonus_that_is_not_an_integer() -> Result<(), Error> {
+        let solution_map: QuerySolution = QuerySolution::from((
+            vec![Variable::new("name")?, Variable::new("strength")?],
+            vec![
+                Term::Literal(Literal::from("a")).into(),
+                Term::Literal(Literal::from("two")).into(),
+            ],
+        ));
+
+        let res = magatama_from_solution(solution_map);
+
+        assert!(res.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_a_magatama_without_bonuses_given_only_a_name() -> Result<(), Error> {
+        let solution_map: QuerySolution = QuerySolution::from((
+            vec![Variable::new("name")?],
+            vec![Term::Literal(Literal::from("a")).into()],
+        ));
+
+        let res = magatama_from_solution(solution_map)?;
+
+        assert_eq!(
+            res,
+            Magatama {
+                name: "a".to_string(),
+                strength: 0,
+                magic: 0,
+                vitality: 0,
+                agility: 0,
+                luck: 0,
+                location: None,
+            }
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod generate_a_prolog_fact_test {
+    use super::*;
+
+    #[test]
+    fn should_return_a_prolog_fact() {
+        let mut magatama = Magatama {
+            name: "a".to_string(),
+            strength: 2,
+            magic: 0,
+            vitality: 1,
+            agility: -1,
+            luck: 0,
+            location: Some("b".to_string()),
+        };
+
+        let res = generate_a_prolog_fact(&magatama, NameRepresentation::Atom);
+        assert_eq!(
+            res.to_fact(),
+            "magatama('a', 2, 0, 1, -1, 0, 'b').".to_string()
+        );
+
+        magatama.location = None;
+        let res = generate_a_prolog_fact(&magatama, NameRepresentation::String);
+        assert_eq!(
+            res.to_fact(),
+            "magatama(\"a\", 2, 0, 1, -1, 0, none).".to_string()
+        );
+    }
+}
+
+#[cfg(test)]
+mod query_magatamas_test {
+    use super::*;
+    use crate::error::ErrorUnknownAffinity;
+    use std::collections::HashSet;
+    use std::path::PathBuf;
+
+    fn to_facts(knowledge_base: &[PrologTerm]) -> HashSet<String> {
+        knowledge_base.iter().map(PrologTerm::to_fact).collect()
+    }
+
+    #[test]
+    fn should_return_a_prolog_magatama_knowledge_base() -> Result<(), Error> {
+        let magatama_file_path = PathBuf::from("./test_files/test_valid_magatama.ttl");
+        let expected_knowledge_base: HashSet<String> = vec![
+            "magatama('Marogareh', 2, 0, 2, 0, 0, 'Shinjuku Medical Center').".to_string(),
+            "magatama('Wadatsumi', 0, 2, 0, 1, 0, none).".to_string(),
+        ]
+        .into_iter()
+        .collect();
+
+        let magatamas =
+            Dataset::load(&[&magatama_file_path]).and_then(|dataset| query_magatamas(&dataset))?;
+        let resp = create_prolog_magatama_knowledge_base(&magatamas, NameRepresentation::Atom);
+
+        assert_eq!(to_facts(&resp), expected_knowledge_base);
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_a_prolog_magatama_affinity_knowledge_base() -> Result<(), Error> {
+        let magatama_file_path = PathBuf::from("./test_files/test_valid_magatama.ttl");
+        let expected_knowledge_base: HashSet<String> = vec![
+            "magatama_affinity('Wadatsumi', 'Ice', null).".to_string(),
+            "magatama_affinity('Wadatsumi', 'Fire', weak).".to_string(),
+        ]
+        .into_iter()
+        .collect();
+
+        let affinities = Dataset::load(&[&magatama_file_path])
+            .and_then(|dataset| query_magatama_affinities(&dataset))?;
+        let resp =
+            create_prolog_magatama_affinity_knowledge_base(&affinities, NameRepresentation::Atom);
+
+        assert_eq!(to_facts(&resp), expected_knowledge_base);
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_a_prolog_magatama_skill_knowledge_base() -> Result<(), Error> {
+        let magatama_file_path = PathBuf::from("./test_files/test_valid_magatama.ttl");
+        let skill_file_path = PathBuf::from("./test_files/test_valid_skill.ttl");
+        let expected_knowledge_base: HashSet<String> = vec![
+            "magatama_skill('Marogareh', 'Lunge', 1).".to_string(),
+            "magatama_skill('Wadatsumi', 'Tarunda', 8).".to_string(),
+        ]
+        .into_iter()
+        .collect();
+
+        let skills = Dataset::load(&[&magatama_file_path, &skill_file_path])
+            .and_then(|dataset| query_magatama_skills(&dataset))?;
+        let resp = create_prolog_magatama_skill_knowledge_base(&skills, NameRepresentation::Atom);
+
+        assert_eq!(to_facts(&resp), expected_knowledge_base);
+        Ok(())
+    }
+
+    #[test]
+    fn should_not_return_the_magatamas_as_demon_stats_or_affinities() -> Result<(), Error> {
+        let magatama_file_path = PathBuf::from("./test_files/test_valid_magatama.ttl");
+
+        let dataset = Dataset::load(&[&magatama_file_path])?;
+
+        assert!(crate::demon_stats_knowledge_base::query_demon_stats(&dataset)?.is_empty());
+        assert!(crate::affinity_knowledge_base::query_affinities(&dataset)?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_an_error_given_an_unknown_affinity() {
+        let magatama_file_path = PathBuf::from("./test_files/test_invalid_magatama.ttl");
+
+        let resp = Dataset::load(&[&magatama_file_path])
+            .and_then(|dataset| query_magatama_affinities(&dataset));
+
+        assert!(resp.unwrap_err().is::<ErrorUnknownAffinity>());
+    }
+}
@@ -17,22 +17,54 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 mod cli;
-mod demon_knowledge_base;
-mod error;
-mod fusion_rule_knowledge_base;
-mod util;
 
 use crate::cli::*;
 use anyhow::Error;
-use demon_knowledge_base::create_prolog_demon_knowledge_base;
-use fusion_rule_knowledge_base::create_prolog_fusion_rule_knowledge_base;
+use smt_nocturne_prolog_kb_generator::affinity_knowledge_base::create_prolog_affinity_knowledge_base;
+use smt_nocturne_prolog_kb_generator::dataset::*;
+use smt_nocturne_prolog_kb_generator::demon_knowledge_base::create_prolog_demon_knowledge_base;
+use smt_nocturne_prolog_kb_generator::demon_stats_knowledge_base::create_prolog_demon_stats_knowledge_base;
+use smt_nocturne_prolog_kb_generator::element_fusion_knowledge_base::*;
+use smt_nocturne_prolog_kb_generator::evolution_knowledge_base::create_prolog_evolution_knowledge_base;
+use smt_nocturne_prolog_kb_generator::fusion::*;
+use smt_nocturne_prolog_kb_generator::fusion_chart_knowledge_base::*;
+use smt_nocturne_prolog_kb_generator::fusion_rule_knowledge_base::create_prolog_fusion_rule_knowledge_base;
+use smt_nocturne_prolog_kb_generator::error::ErrorUnknownDemon;
+use smt_nocturne_prolog_kb_generator::error::ErrorUnknownSkill;
+use smt_nocturne_prolog_kb_generator::error::ErrorUnusedPartySkills;
+use smt_nocturne_prolog_kb_generator::inheritance_knowledge_base::create_prolog_inheritance_knowledge_base;
+use smt_nocturne_prolog_kb_generator::magatama_knowledge_base::*;
+use smt_nocturne_prolog_kb_generator::mitama_fusion_knowledge_base::create_prolog_mitama_fusion_knowledge_base;
+use smt_nocturne_prolog_kb_generator::model::*;
+use smt_nocturne_prolog_kb_generator::phase_condition_knowledge_base::create_prolog_phase_condition_knowledge_base;
+use smt_nocturne_prolog_kb_generator::planner::*;
+use smt_nocturne_prolog_kb_generator::prolog::*;
+use smt_nocturne_prolog_kb_generator::recruitment_knowledge_base::*;
+use smt_nocturne_prolog_kb_generator::simulation::*;
+use smt_nocturne_prolog_kb_generator::skill_knowledge_base::*;
+use smt_nocturne_prolog_kb_generator::special_fusion_knowledge_base::create_prolog_special_fusion_knowledge_base;
 
 fn main() -> Result<(), Error> {
     let CliArg {
         demon_rdf_file_path,
         race_rdf_file_path,
         fusion_rule_rdf_file_path,
+        special_fusion_rdf_file_path,
+        skill_rdf_file_path,
+        magatama_rdf_file_path,
+        demon_rdf_format,
+        race_rdf_format,
+        fusion_rule_rdf_format,
+        special_fusion_rdf_format,
+        skill_rdf_format,
+        magatama_rdf_format,
+        merge_named_graphs,
+        name_representation,
+        module_name,
+        fusion_chart,
+        fusion_recipes,
         license,
+        command,
     }: CliArg = argh::from_env();
 
     if license {
@@ -40,28 +72,390 @@ fn main() -> Result<(), Error> {
         return Ok(());
     }
 
-    let demon_knowledge_base =
-        create_prolog_demon_knowledge_base(&demon_rdf_file_path, &race_rdf_file_path)?;
+    let named_graphs = if merge_named_graphs {
+        NamedGraphs::Merge
+    } else {
+        NamedGraphs::Preserve
+    };
+    let mut rdf_files = vec![
+        RdfFile::new(&demon_rdf_file_path, demon_rdf_format),
+        RdfFile::new(&race_rdf_file_path, race_rdf_format),
+        RdfFile::new(&fusion_rule_rdf_file_path, fusion_rule_rdf_format),
+    ];
+    if let Some(path) = &special_fusion_rdf_file_path {
+        rdf_files.push(RdfFile::new(path, special_fusion_rdf_format));
+    }
+    if let Some(path) = &skill_rdf_file_path {
+        rdf_files.push(RdfFile::new(path, skill_rdf_format));
+    }
+    if let Some(path) = &magatama_rdf_file_path {
+        rdf_files.push(RdfFile::new(path, magatama_rdf_format));
+    }
+    let dataset = Dataset::load_files(&rdf_files, named_graphs)?;
+    let model = Model::load(&dataset)?;
+
+    match command {
+        Some(Command::Recipe(RecipeCommand { demon, max_level })) => {
+            let engine = FusionEngine::new(&model);
+            let recipes = engine.fusion_recipes(&demon, max_level)?;
+            if recipes.is_empty() {
+                println!("no normal fusion produces '{demon}'");
+            } else {
+                println!("{}", recipes_to_text(&recipes));
+            }
+            return Ok(());
+        }
+        Some(Command::Plan(PlanCommand {
+            demon,
+            party,
+            compendium,
+            max_steps,
+            phase,
+            skill,
+            prolog,
+        })) => {
+            let target = find_demon(&model, &demon)?;
+            let compendium = find_demons(&model, &compendium)?;
+            let mut planner = FusionPlanner::new(&model).with_compendium(&compendium);
+            if let Some(max_steps) = max_steps {
+                planner = planner.with_max_steps(max_steps);
+            }
+            if let Some(phase) = phase {
+                planner = planner.with_phase(phase);
+            }
+            let plan = if skill.is_empty() {
+                let party = find_party(&model, &party)?;
+                planner.shortest_plan(&party, target)?
+            } else {
+                let party = find_party_demons(&model, &planner, &party)?;
+                let skills = find_skills(&model, &skill)?;
+                match planner.shortest_plan_with_skills(&party, target, &skills)? {
+                    SkillPlan::Reachable(plan) => Some(plan),
+                    SkillPlan::Unreachable(unreachability) => {
+                        println!(
+                            "{}",
+                            unreachability_to_text(target, &skills, &unreachability)
+                        );
+                        return Ok(());
+                    }
+                }
+            };
+            match plan {
+                Some(plan) if prolog => println!(
+                    "{}",
+                    plan_to_prolog_term(&plan, name_representation)?
+                ),
+                Some(plan) if plan.actions.is_empty() => {
+                    println!("the party already has '{demon}'")
+                }
+                Some(plan) => {
+                    println!("{}", plan_to_text(&plan));
+                    if let Some(phase) = phase {
+                        let risk = FusionSimulator::new(&model, phase, 0).plan_risk(&plan);
+                        if risk > 0.0 {
+                            println!("risky: {:.1}% chance of a fusion accident", risk * 100.0);
+                        }
+                    }
+                }
+                None => println!("no sequence of actions produces '{demon}'"),
+            }
+            return Ok(());
+        }
+        Some(Command::Simulate(SimulateCommand {
+            demon1,
+            demon2,
+            seed,
+            phase,
+            count,
+        })) => {
+            let demon1 = find_demon(&model, &demon1)?;
+            let demon2 = find_demon(&model, &demon2)?;
+            let mut simulator = FusionSimulator::new(&model, phase, seed);
+            let outcomes = simulator.outcome_distribution(demon1, demon2);
+            if outcomes.is_empty() {
+                println!("'{}' and '{}' cannot be fused", demon1.name, demon2.name);
+                return Ok(());
+            }
+            let simulated: Vec<SimulatedFusion> = (0..count)
+                .filter_map(|_| simulator.simulate(demon1, demon2))
+                .collect();
+            println!("{}", simulation_to_text(&simulated, &outcomes));
+            return Ok(());
+        }
+        None => {}
+    }
 
-    let fusion_rule_knowledge_base =
-        create_prolog_fusion_rule_knowledge_base(&fusion_rule_rdf_file_path, &race_rdf_file_path)?;
+    let mut sections = knowledge_base_sections(&model, name_representation);
+    if fusion_chart {
+        sections.push(KnowledgeBaseSection {
+            predicate: PredicateIndicator::new("fusion_result", 3),
+            doc: DOC_FUSION_RESULT,
+            facts: create_prolog_fusion_chart_knowledge_base(&model, name_representation),
+        });
+    }
+    if fusion_recipes {
+        sections.push(KnowledgeBaseSection {
+            predicate: PredicateIndicator::new("fusion_recipe", 3),
+            doc: DOC_FUSION_RECIPE,
+            facts: create_prolog_fusion_recipe_knowledge_base(&model, name_representation),
+        });
+    }
 
-    let knowledge_base: Vec<String> = [LICENSE_PROLOG_FILE.to_string()]
-        .into_iter()
-        .chain([DOC_KNOWLEDGE_BASE.to_string(), "".to_string()])
-        .into_iter()
-        .chain([DOC_DEMON_RULE.to_string()])
-        .chain(demon_knowledge_base)
-        .chain(["".to_string()])
-        .chain([DOC_FUSION_RULE.to_string()])
-        .chain(fusion_rule_knowledge_base)
+    let declared_predicates: Vec<PredicateIndicator> = sections
+        .iter()
+        .map(|section| section.predicate.clone())
+        .collect();
+    let facts: Vec<PrologTerm> = sections
+        .iter()
+        .flat_map(|section| section.facts.iter().cloned())
         .collect();
 
+    let mut knowledge_base: Vec<String> = vec![LICENSE_PROLOG_FILE.to_string()];
+    knowledge_base.extend(module_directives(
+        &module_name,
+        &declared_predicates,
+        &facts,
+    ));
+    knowledge_base.extend(["".to_string(), DOC_KNOWLEDGE_BASE.to_string()]);
+    for section in sections {
+        knowledge_base.extend(["".to_string(), section.doc.to_string()]);
+        knowledge_base.extend(section.facts.iter().map(PrologTerm::to_fact));
+    }
+
     println!("{}", knowledge_base.join("\n"));
     Ok(())
 }
 
-const DOC_KNOWLEDGE_BASE: &'static str = r#"/*
+/// The sections of the knowledge base, without the optional fusion chart and fusion recipes.
+fn knowledge_base_sections(
+    model: &Model,
+    name_representation: NameRepresentation,
+) -> Vec<KnowledgeBaseSection> {
+    vec![
+        KnowledgeBaseSection {
+            predicate: PredicateIndicator::new("demon", 4),
+            doc: DOC_DEMON_RULE,
+            facts: create_prolog_demon_knowledge_base(&model.demons, name_representation),
+        },
+        KnowledgeBaseSection {
+            predicate: PredicateIndicator::new("demon_stats", 6),
+            doc: DOC_DEMON_STATS,
+            facts: create_prolog_demon_stats_knowledge_base(
+                &model.demon_stats,
+                name_representation,
+            ),
+        },
+        KnowledgeBaseSection {
+            predicate: PredicateIndicator::new("affinity", 3),
+            doc: DOC_AFFINITY,
+            facts: create_prolog_affinity_knowledge_base(&model.affinities, name_representation),
+        },
+        KnowledgeBaseSection {
+            predicate: PredicateIndicator::new("skill", 5),
+            doc: DOC_SKILL,
+            facts: create_prolog_skill_knowledge_base(&model.skills, name_representation),
+        },
+        KnowledgeBaseSection {
+            predicate: PredicateIndicator::new("skill_category", 2),
+            doc: DOC_SKILL_CATEGORY,
+            facts: create_prolog_skill_category_knowledge_base(&model.skills, name_representation),
+        },
+        KnowledgeBaseSection {
+            predicate: PredicateIndicator::new("learns", 3),
+            doc: DOC_LEARNS,
+            facts: create_prolog_learned_skill_knowledge_base(
+                &model.learned_skills,
+                name_representation,
+            ),
+        },
+        KnowledgeBaseSection {
+            predicate: PredicateIndicator::new("magatama", 7),
+            doc: DOC_MAGATAMA,
+            facts: create_prolog_magatama_knowledge_base(&model.magatamas, name_representation),
+        },
+        KnowledgeBaseSection {
+            predicate: PredicateIndicator::new("magatama_affinity", 3),
+            doc: DOC_MAGATAMA_AFFINITY,
+            facts: create_prolog_magatama_affinity_knowledge_base(
+                &model.magatama_affinities,
+                name_representation,
+            ),
+        },
+        KnowledgeBaseSection {
+            predicate: PredicateIndicator::new("magatama_skill", 3),
+            doc: DOC_MAGATAMA_SKILL,
+            facts: create_prolog_magatama_skill_knowledge_base(
+                &model.magatama_skills,
+                name_representation,
+            ),
+        },
+        KnowledgeBaseSection {
+            predicate: PredicateIndicator::new("evolve", 3),
+            doc: DOC_EVOLUTION,
+            facts: create_prolog_evolution_knowledge_base(&model.evolutions, name_representation),
+        },
+        KnowledgeBaseSection {
+            predicate: PredicateIndicator::new("recruitable", 2),
+            doc: DOC_RECRUITMENT,
+            facts: create_prolog_recruitment_knowledge_base(
+                &model.recruitments,
+                name_representation,
+            ),
+        },
+        KnowledgeBaseSection {
+            predicate: PredicateIndicator::new("recruitable", 3),
+            doc: DOC_RECRUITMENT_PHASE,
+            facts: create_prolog_recruitment_phase_knowledge_base(
+                &model.recruitments,
+                name_representation,
+            ),
+        },
+        KnowledgeBaseSection {
+            predicate: PredicateIndicator::new("fuse_race", 3),
+            doc: DOC_FUSION_RULE,
+            facts: create_prolog_fusion_rule_knowledge_base(
+                &model.race_fusion_rules,
+                name_representation,
+            ),
+        },
+        KnowledgeBaseSection {
+            predicate: PredicateIndicator::new("fuse_element", 2),
+            doc: DOC_ELEMENT_FUSION_RULE,
+            facts: create_prolog_element_fusion_knowledge_base(
+                &model.element_fusion_rules,
+                name_representation,
+            ),
+        },
+        KnowledgeBaseSection {
+            predicate: PredicateIndicator::new("element_rank_shift", 3),
+            doc: DOC_ELEMENT_RANK_SHIFT_RULE,
+            facts: create_prolog_element_rank_shift_knowledge_base(
+                &model.element_rank_shift_rules,
+                name_representation,
+            ),
+        },
+        KnowledgeBaseSection {
+            predicate: PredicateIndicator::new("can_inherit", 2),
+            doc: DOC_CAN_INHERIT,
+            facts: create_prolog_inheritance_knowledge_base(
+                &model.inheritance_rules,
+                name_representation,
+            ),
+        },
+        KnowledgeBaseSection {
+            predicate: PredicateIndicator::new("mitama_fusion", 3),
+            doc: DOC_MITAMA_FUSION_RULE,
+            facts: create_prolog_mitama_fusion_knowledge_base(
+                &model.mitama_fusion_rules,
+                name_representation,
+            ),
+        },
+        KnowledgeBaseSection {
+            predicate: PredicateIndicator::new("special_fusion", 3),
+            doc: DOC_SPECIAL_FUSION,
+            facts: create_prolog_special_fusion_knowledge_base(
+                &model.special_fusion_recipes,
+                name_representation,
+            ),
+        },
+        KnowledgeBaseSection {
+            predicate: PredicateIndicator::new("phase_condition", 2),
+            doc: DOC_PHASE_CONDITION,
+            facts: create_prolog_phase_condition_knowledge_base(model, name_representation),
+        },
+    ]
+}
+
+fn find_demon<'a>(model: &'a Model, name: &str) -> Result<&'a Demon, ErrorUnknownDemon> {
+    model.demon(name).ok_or_else(|| ErrorUnknownDemon {
+        name: name.to_string(),
+    })
+}
+
+fn find_demons<'a>(model: &'a Model, names: &[String]) -> Result<Vec<&'a Demon>, ErrorUnknownDemon> {
+    names.iter().map(|name| find_demon(model, name)).collect()
+}
+
+/// The demons of the party without skills, rejecting the members followed by ':' and skills since no skill is
+/// required.
+fn find_party<'a>(model: &'a Model, members: &[String]) -> Result<Vec<&'a Demon>, Error> {
+    members
+        .iter()
+        .map(|member| {
+            if member.contains(':') {
+                return Err(ErrorUnusedPartySkills {
+                    member: member.clone(),
+                }
+                .into());
+            }
+            Ok(find_demon(model, member)?)
+        })
+        .collect()
+}
+
+/// The demons of the party, written as their name optionally followed by ':' and the comma-separated skills they
+/// carry, carrying the skills they learn at their base level otherwise.
+fn find_party_demons<'a>(
+    model: &'a Model,
+    planner: &FusionPlanner<'a>,
+    party: &[String],
+) -> Result<Vec<PartyDemon<'a>>, Error> {
+    let mut party_demons = Vec::new();
+    for member in party {
+        let party_demon = match member.split_once(':') {
+            Some((name, skills)) => {
+                let skills: Vec<String> = skills
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|skill| !skill.is_empty())
+                    .map(String::from)
+                    .collect();
+                PartyDemon::new(find_demon(model, name.trim())?, find_skills(model, &skills)?)
+            }
+            None => planner.innate_party_demon(find_demon(model, member)?),
+        };
+        party_demons.push(party_demon);
+    }
+    Ok(party_demons)
+}
+
+fn find_skills<'a>(model: &'a Model, names: &[String]) -> Result<Vec<&'a Skill>, ErrorUnknownSkill> {
+    names
+        .iter()
+        .map(|name| {
+            model.skill(name).ok_or_else(|| ErrorUnknownSkill {
+                name: name.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// The recipes of a demon, one line per pair of demons under the pair of races.
+fn recipes_to_text(recipes: &[FusionRecipes]) -> String {
+    let mut lines = Vec::new();
+    for recipe in recipes {
+        lines.push(format!("{} x {}", recipe.race1, recipe.race2));
+        for fusion in &recipe.fusions {
+            lines.push(format!(
+                "  {} (Lv {}) + {} (Lv {})",
+                fusion.demon1.name,
+                fusion.demon1.base_level,
+                fusion.demon2.name,
+                fusion.demon2.base_level
+            ));
+        }
+    }
+    lines.join("\n")
+}
+
+/// A section of the knowledge base describing a predicate.
+struct KnowledgeBaseSection {
+    predicate: PredicateIndicator,
+    doc: &'static str,
+    facts: Vec<PrologTerm>,
+}
+
+const DOC_KNOWLEDGE_BASE: &str = r#"/*
 Knowledge Base: Demon Fusion System
 
 This knowledge base models demons from the video game *Shin Megami Tensei: Nocturne* 
@@ -69,7 +463,7 @@ and their fusion rules. Each demon has a name, race, base level,
 and an indicator of whether special fusion is required.
 */"#;
 
-const DOC_DEMON_RULE: &'static str = r#"/**
+const DOC_DEMON_RULE: &str = r#"/**
  * demon(Name, Race, BaseLv, SpecialFusion)
  *
  * Describes a demon.
@@ -80,7 +474,127 @@ const DOC_DEMON_RULE: &'static str = r#"/**
  * @param SpecialFusion Whether the demon requires special fusion.
  */"#;
 
-const DOC_FUSION_RULE: &'static str = r#"/**
+const DOC_DEMON_STATS: &str = r#"/**
+ * demon_stats(Name, St, Ma, Vi, Ag, Lu)
+ *
+ * Describes the base stats of a demon.
+ *
+ * @param Name The name of the demon.
+ * @param St   The strength of the demon.
+ * @param Ma   The magic of the demon.
+ * @param Vi   The vitality of the demon.
+ * @param Ag   The agility of the demon.
+ * @param Lu   The luck of the demon.
+ */"#;
+
+const DOC_AFFINITY: &str = r#"/**
+ * affinity(Name, Element, Affinity)
+ *
+ * Describes the reaction of a demon to the attacks of an element.
+ * A demon takes normal damage from the elements without affinity.
+ *
+ * @param Name     The name of the demon.
+ * @param Element  The name of the element, like `Phys`, `Fire`, `Ice`, `Elec`, `Force`,
+ *                 `Expel`, `Death`, `Mind`, `Nerve` or `Curse`.
+ * @param Affinity The affinity, either `weak`, `resist`, `null`, `repel` or `drain`.
+ */"#;
+
+const DOC_SKILL: &str = r#"/**
+ * skill(Name, Element, Cost, Power, Target)
+ *
+ * Describes a skill.
+ *
+ * @param Name    The name of the skill.
+ * @param Element The name of the element of the skill.
+ * @param Cost    The cost of the skill, either `mp(Cost)`, `hp(Cost)` or `none` for a passive skill.
+ * @param Power   The power of the skill, or `none` for a skill without power.
+ * @param Target  The target of the skill.
+ */"#;
+
+const DOC_SKILL_CATEGORY: &str = r#"/**
+ * skill_category(Skill, Category)
+ *
+ * Describes the category of a skill, which restricts its inheritance.
+ *
+ * @param Skill    The name of the skill.
+ * @param Category The name of the category of the skill.
+ */"#;
+
+const DOC_LEARNS: &str = r#"/**
+ * learns(Demon, Skill, Level)
+ *
+ * Describes a skill learned by a demon.
+ *
+ * @param Demon The name of the demon.
+ * @param Skill The name of the skill.
+ * @param Level The level at which the demon learns the skill, its base level for an innate skill.
+ */"#;
+
+const DOC_MAGATAMA: &str = r#"/**
+ * magatama(Name, St, Ma, Vi, Ag, Lu, Location)
+ *
+ * Describes a Magatama, which the Demi-fiend swallows to gain its bonuses, affinities and skills.
+ *
+ * @param Name     The name of the Magatama.
+ * @param St       The strength bonus of the Magatama.
+ * @param Ma       The magic bonus of the Magatama.
+ * @param Vi       The vitality bonus of the Magatama.
+ * @param Ag       The agility bonus of the Magatama.
+ * @param Lu       The luck bonus of the Magatama.
+ * @param Location The place where the Magatama is obtained, or `none` when it is unknown.
+ */"#;
+
+const DOC_MAGATAMA_AFFINITY: &str = r#"/**
+ * magatama_affinity(Magatama, Element, Affinity)
+ *
+ * Describes the reaction of the Demi-fiend to the attacks of an element while it carries a Magatama.
+ *
+ * @param Magatama The name of the Magatama.
+ * @param Element  The name of the element.
+ * @param Affinity The affinity, either `weak`, `resist`, `null`, `repel` or `drain`.
+ */"#;
+
+const DOC_MAGATAMA_SKILL: &str = r#"/**
+ * magatama_skill(Magatama, Skill, Level)
+ *
+ * Describes a skill taught by a Magatama to the Demi-fiend.
+ *
+ * @param Magatama The name of the Magatama.
+ * @param Skill    The name of the skill.
+ * @param Level    The level of the Demi-fiend at which the Magatama teaches the skill.
+ */"#;
+
+const DOC_EVOLUTION: &str = r#"/**
+ * evolve(From, To, AtLevel)
+ *
+ * Describes the evolution of a demon into another demon.
+ *
+ * @param From    The name of the evolving demon.
+ * @param To      The name of the demon it evolves into.
+ * @param AtLevel The level at which the demon evolves.
+ */"#;
+
+const DOC_RECRUITMENT: &str = r#"/**
+ * recruitable(Name, Area)
+ *
+ * Describes an area of the field where a demon can be recruited.
+ *
+ * @param Name The name of the demon.
+ * @param Area The name of the area.
+ */"#;
+
+const DOC_RECRUITMENT_PHASE: &str = r#"/**
+ * recruitable(Name, Area, Phase)
+ *
+ * Describes a phase of Kagutsuchi during which a demon can be recruited in an area,
+ * when its recruitment in the area is restricted to some phases.
+ *
+ * @param Name  The name of the demon.
+ * @param Area  The name of the area.
+ * @param Phase The phase of Kagutsuchi, from 0 (new) to 8 (full).
+ */"#;
+
+const DOC_FUSION_RULE: &str = r#"/**
  * fuse_race(Race1, Race2, Result)
  *
  * Describes the fusion of two races.
@@ -89,7 +603,96 @@ const DOC_FUSION_RULE: &'static str = r#"/**
  * @param Race2  The second race.
  * @param Result The resulting race after fusion.
  */"#;
-const LICENSE_PROLOG_FILE: &'static str = r#"% smt-nocturne-prolog-kb-generator: A generates of a Prolog knowledge base
+
+const DOC_ELEMENT_FUSION_RULE: &str = r#"/**
+ * fuse_element(Race, Element)
+ *
+ * Describes the element demon resulting from the fusion of two demons of the same race.
+ *
+ * @param Race    The race of the two demons.
+ * @param Element The name of the resulting element demon.
+ */"#;
+
+const DOC_ELEMENT_RANK_SHIFT_RULE: &str = r#"/**
+ * element_rank_shift(Race, Element, Shift)
+ *
+ * Describes how the fusion of a demon with an element demon moves it inside its race,
+ * the result being the demon `Shift` ranks above it ordered by base level, or below it when `Shift` is negative.
+ *
+ * @param Race    The race of the demon.
+ * @param Element The name of the element demon.
+ * @param Shift   The number of ranks the demon moves.
+ */"#;
+
+const DOC_CAN_INHERIT: &str = r#"/**
+ * can_inherit(Race, Category)
+ *
+ * Describes a category of skills that the demons of a race can inherit through fusion.
+ * The result of a fusion can inherit a skill of the fused demons when its race can inherit
+ * the category of the skill given by `skill_category/2`.
+ *
+ * @param Race     The name of the race.
+ * @param Category The name of the skill category.
+ */"#;
+
+const DOC_MITAMA_FUSION_RULE: &str = r#"/**
+ * mitama_fusion(Mitama, Stat, Boost)
+ *
+ * Describes a stat boosted by the fusion of a demon with a Mitama.
+ * The fusion keeps the demon instead of producing a new one.
+ *
+ * @param Mitama The name of the Mitama.
+ * @param Stat   The boosted stat, one of `st`, `ma`, `vi`, `ag` or `lu`.
+ * @param Boost  The number of points added to the stat.
+ */"#;
+
+const DOC_SPECIAL_FUSION: &str = r#"/**
+ * special_fusion(Result, Ingredients, Conditions)
+ *
+ * Describes a recipe to obtain a demon with a special fusion.
+ * A demon can have many recipes, and a recipe possible during many phases of Kagutsuchi is described
+ * by a fact per phase.
+ *
+ * @param Result      The name of the resulting demon.
+ * @param Ingredients The names of the demons to fuse, sorted by name, a demon used twice appearing twice.
+ * @param Conditions  The conditions of the fusion, either `kagutsuchi_phase(Phase)` with `Phase`
+ *                    from 0 (new) to 8 (full), or `prerequisite(Description)` for a story prerequisite.
+ */"#;
+
+const DOC_PHASE_CONDITION: &str = r#"/**
+ * phase_condition(Fusion, Phase)
+ *
+ * Describes a phase of Kagutsuchi during which a fusion can be performed.
+ *
+ * @param Fusion The kind of fusion, either `normal_fusion`, `mitama_fusion`, `sacrificial_fusion`
+ *               or `special_fusion(Result)` for the special fusions of the demon `Result`.
+ * @param Phase  The phase of Kagutsuchi, from 0 (new) to 8 (full).
+ */"#;
+
+const DOC_FUSION_RESULT: &str = r#"/**
+ * fusion_result(Name1, Name2, Result)
+ *
+ * Describes the result of the normal fusion of two demons.
+ * Each pair of demons is described once with its demons ordered by name,
+ * so the fusion of `Name1` and `Name2` is also the fusion of `Name2` and `Name1`.
+ *
+ * @param Name1  The name of the first demon.
+ * @param Name2  The name of the second demon.
+ * @param Result The name of the resulting demon.
+ */"#;
+
+const DOC_FUSION_RECIPE: &str = r#"/**
+ * fusion_recipe(Result, Name1, Name2)
+ *
+ * Describes a pair of demons producing a demon with the normal fusion.
+ * Each pair of demons is described once with its demons ordered by name.
+ *
+ * @param Result The name of the resulting demon.
+ * @param Name1  The name of the first demon.
+ * @param Name2  The name of the second demon.
+ */"#;
+
+const LICENSE_PROLOG_FILE: &str = r#"% smt-nocturne-prolog-kb-generator: A generates of a Prolog knowledge base
 % to describe demons, their fusion, and fusion mechanics for the game 
 % Shin Megami Tensei III: Nocturne.
 % Copyright (C) 2025  Bryan-Elliott Tam
@@ -107,7 +710,7 @@ const LICENSE_PROLOG_FILE: &'static str = r#"% smt-nocturne-prolog-kb-generator:
 % You should have received a copy of the GNU General Public License
 % along with this program.  If not, see <https://www.gnu.org/licenses/>.
 "#;
-const LICENSE: &'static str = r#"smt-nocturne-prolog-kb-generator: A generates of a Prolog knowledge base
+const LICENSE: &str = r#"smt-nocturne-prolog-kb-generator: A generates of a Prolog knowledge base
 to describe demons, their fusion, and fusion mechanics for the game 
 Shin Megami Tensei III: Nocturne.
 Copyright (C) 2025  Bryan-Elliott Tam
@@ -124,3 +727,104 @@ GNU General Public License for more details.
 
 You should have received a copy of the GNU General Public License
 along with this program.  If not, see <https://www.gnu.org/licenses/>."#;
+
+#[cfg(test)]
+mod find_party_test {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn a_model() -> Result<Model, Error> {
+        let dataset = Dataset::load(&[
+            &PathBuf::from("./test_files/test_valid_demon.ttl"),
+            &PathBuf::from("./test_files/test_valid_demon_stats.ttl"),
+            &PathBuf::from("./test_files/test_valid_race.ttl"),
+        ])?;
+        Model::load(&dataset)
+    }
+
+    #[test]
+    fn should_return_the_demons_of_the_party() -> Result<(), Error> {
+        let model = a_model()?;
+
+        let party = find_party(&model, &["Aeros".to_string(), "Abaddon".to_string()])?;
+
+        assert_eq!(
+            party,
+            vec![model.demon("Aeros").unwrap(), model.demon("Abaddon").unwrap()]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_an_error_given_a_member_with_skills() -> Result<(), Error> {
+        let model = a_model()?;
+
+        let res = find_party(&model, &["Aeros:Agi".to_string()]);
+
+        assert_eq!(
+            res.err().map(|error| error.to_string()),
+            Some(
+                "the party demon 'Aeros:Agi' carries skills but no skill is required with --skill"
+                    .to_string()
+            )
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod knowledge_base_sections_test {
+    use super::*;
+    use std::path::PathBuf;
+
+    const PLANNER: &str = include_str!("../dataset/prolog/planner.pl");
+
+    /// Whether the Prolog source calls or matches the predicate `name`.
+    fn uses(source: &str, name: &str) -> bool {
+        source.match_indices(&format!("{name}(")).any(|(i, _)| {
+            !source[..i]
+                .chars()
+                .next_back()
+                .is_some_and(|c| c.is_alphanumeric() || c == '_')
+        })
+    }
+
+    /// The predicates of the knowledge base used by the planner.
+    fn used_by_the_planner(sections: &[KnowledgeBaseSection]) -> Vec<&PredicateIndicator> {
+        sections
+            .iter()
+            .map(|section| &section.predicate)
+            .filter(|predicate| uses(PLANNER, &predicate.name))
+            .collect()
+    }
+
+    #[test]
+    fn should_generate_facts_for_the_predicates_used_by_the_planner() -> Result<(), Error> {
+        let demon_file_path = PathBuf::from("./test_files/test_valid_demon.ttl");
+        let stats_file_path = PathBuf::from("./test_files/test_valid_demon_stats.ttl");
+        let race_file_path = PathBuf::from("./test_files/test_valid_race.ttl");
+        let rule_file_path = PathBuf::from("./test_files/test_valid_rule.ttl");
+        let dataset = Dataset::load(&[
+            &demon_file_path,
+            &stats_file_path,
+            &race_file_path,
+            &rule_file_path,
+        ])?;
+        let model = Model::load(&dataset)?;
+
+        let sections = knowledge_base_sections(&model, NameRepresentation::Atom);
+        let used = used_by_the_planner(&sections);
+
+        assert!(!used.is_empty());
+        for section in &sections {
+            if used.contains(&&section.predicate) {
+                assert!(
+                    !section.facts.is_empty(),
+                    "the planner uses {} without facts",
+                    section.predicate
+                );
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,189 @@
+// smt-nocturne-prolog-kb-generator: A generates of a Prolog knowledge base
+// to describe demons, their fusion, and fusion mechanics for the game 
+// Shin Megami Tensei III: Nocturne.
+// Copyright (C) 2025  Bryan-Elliott Tam
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::dataset::Dataset;
+use crate::model::*;
+use crate::prolog::*;
+use crate::util::*;
+use anyhow::Error;
+use oxigraph::sparql::QuerySolution;
+
+/// Query the Shin Megami Tensei Nocture rules describing the stats boosted by the fusion with a Mitama.
+pub fn query_mitama_fusion_rules(dataset: &Dataset) -> Result<Vec<MitamaFusionRule>, Error> {
+    query_model(
+        dataset,
+        GET_MITAMA_FUSION_RULE,
+        mitama_fusion_rule_from_solution,
+    )
+}
+
+/// create a prolog knowledge based containing the stats boosted by the fusion of Shin Megami Tensei Nocture demons with a Mitama.
+pub fn create_prolog_mitama_fusion_knowledge_base(
+    mitama_fusion_rules: &[MitamaFusionRule],
+    name_representation: NameRepresentation,
+) -> Vec<PrologTerm> {
+    create_prolog_knowledge_base(mitama_fusion_rules, |rule| {
+        generate_a_prolog_fact(rule, name_representation)
+    })
+}
+
+fn mitama_fusion_rule_from_solution(
+    solution_map: QuerySolution,
+) -> Result<MitamaFusionRule, Error> {
+    let mitama = solution_variable(&solution_map, "mitama")?;
+    let stat = solution_variable(&solution_map, "stat")?;
+    let boost = solution_variable(&solution_map, "boost")?;
+
+    Ok(MitamaFusionRule {
+        mitama: literal_string_to_string(mitama, "mitama")?,
+        stat: literal_string_to_string(stat, "stat")?,
+        boost: literal_integer_to_i64(boost, "boost")?,
+    })
+}
+
+fn generate_a_prolog_fact(
+    rule: &MitamaFusionRule,
+    name_representation: NameRepresentation,
+) -> PrologTerm {
+    PrologTerm::compound(
+        "mitama_fusion",
+        vec![
+            name_representation.term(&rule.mitama),
+            PrologTerm::Atom(rule.stat.to_lowercase()),
+            PrologTerm::Integer(rule.boost),
+        ],
+    )
+}
+
+const GET_MITAMA_FUSION_RULE: &str = "
+PREFIX vocab: <https://constraintautomaton.github.io/smt-nocture-db-to-rdf/vocabulary.ttl#>
+PREFIX schema: <https://schema.org/>
+
+SELECT ?mitama ?stat ?boost WHERE {
+    [] vocab:withMitama ?mitamaIri ;
+        vocab:boostedStat ?stat ;
+        vocab:statBoost ?boost .
+
+    ?mitamaIri schema:name ?mitama .
+}";
+
+#[cfg(test)]
+mod mitama_fusion_rule_from_solution_test {
+    use super::*;
+    use oxigraph::model::*;
+
+    #[test]
+    fn should_return_an_error_given_the_boost_is_not_an_integer() -> Result<(), Error> {
+        let solution_map: QuerySolution = QuerySolution::from((
+            vec![
+                Variable::new("mitama")?,
+                Variable::new("stat")?,
+                Variable::new("boost")?,
+            ],
+            vec![
+                Term::Literal(Literal::from("Ara Mitama")).into(),
+                Term::Literal(Literal::from("St")).into(),
+                Term::Literal(Literal::from("one")).into(),
+            ],
+        ));
+
+        let res = mitama_fusion_rule_from_solution(solution_map);
+
+        assert_eq!(
+            res.err().map(|error| error.to_string()),
+            Some("the value of the variable 'boost' is not an integer".to_string())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_an_error_given_the_stat_is_not_in_the_solution_map() -> Result<(), Error> {
+        let solution_map: QuerySolution = QuerySolution::from((
+            vec![Variable::new("mitama")?, Variable::new("boost")?],
+            vec![
+                Term::Literal(Literal::from("Ara Mitama")).into(),
+                Term::Literal(Literal::from(1)).into(),
+            ],
+        ));
+
+        let res = mitama_fusion_rule_from_solution(solution_map);
+
+        assert_eq!(
+            res.err().map(|error| error.to_string()),
+            Some("the variable 'stat' does not exist in the solution map".to_string())
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod generate_a_prolog_fact_test {
+    use super::*;
+
+    #[test]
+    fn should_return_a_prolog_fact() {
+        let rule = MitamaFusionRule {
+            mitama: "Ara Mitama".to_string(),
+            stat: "St".to_string(),
+            boost: 1,
+        };
+
+        let res = generate_a_prolog_fact(&rule, NameRepresentation::Atom);
+        assert_eq!(
+            res.to_fact(),
+            "mitama_fusion('Ara Mitama', st, 1).".to_string()
+        );
+
+        let res = generate_a_prolog_fact(&rule, NameRepresentation::String);
+        assert_eq!(
+            res.to_fact(),
+            "mitama_fusion(\"Ara Mitama\", st, 1).".to_string()
+        );
+    }
+}
+
+#[cfg(test)]
+mod query_mitama_fusion_rules_test {
+    use super::*;
+    use std::collections::HashSet;
+    use std::path::PathBuf;
+
+    #[test]
+    fn should_return_a_prolog_knowledge_base() -> Result<(), Error> {
+        let mitama_rule_file_path = PathBuf::from("./test_files/test_valid_mitama_rule.ttl");
+        let expected_knowledge_base: HashSet<String> = vec![
+            "mitama_fusion('Ara Mitama', st, 1).".to_string(),
+            "mitama_fusion('Ara Mitama', vi, 1).".to_string(),
+            "mitama_fusion('Nigi Mitama', ma, 2).".to_string(),
+        ]
+        .into_iter()
+        .collect();
+
+        let rules = Dataset::load(&[&mitama_rule_file_path])
+            .and_then(|dataset| query_mitama_fusion_rules(&dataset))?;
+        let resp = create_prolog_mitama_fusion_knowledge_base(&rules, NameRepresentation::Atom);
+
+        assert_eq!(
+            resp.iter()
+                .map(PrologTerm::to_fact)
+                .collect::<HashSet<String>>(),
+            expected_knowledge_base
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,803 @@
+// smt-nocturne-prolog-kb-generator: A generates of a Prolog knowledge base
+// to describe demons, their fusion, and fusion mechanics for the game 
+// Shin Megami Tensei III: Nocturne.
+// Copyright (C) 2025  Bryan-Elliott Tam
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::affinity_knowledge_base::*;
+use crate::dataset::Dataset;
+use crate::demon_knowledge_base::*;
+use crate::demon_stats_knowledge_base::*;
+use crate::element_fusion_knowledge_base::*;
+use crate::evolution_knowledge_base::*;
+use crate::fusion_rule_knowledge_base::*;
+use crate::inheritance_knowledge_base::*;
+use crate::magatama_knowledge_base::*;
+use crate::mitama_fusion_knowledge_base::*;
+use crate::recruitment_knowledge_base::*;
+use crate::skill_knowledge_base::*;
+use crate::special_fusion_knowledge_base::*;
+use crate::error::{ErrorInvalidKagutsuchiPhase, ErrorUnknownAffinity};
+use anyhow::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// A demon of Shin Megami Tensei III: Nocturne.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Demon {
+    /// The IRI of the demon in the RDF dataset.
+    pub iri: String,
+    /// The name of the demon, which is also its identifier.
+    pub name: String,
+    /// The name of the race of the demon.
+    pub race: String,
+    /// The base level of the demon.
+    pub base_level: i64,
+    /// Whether the demon requires a special fusion.
+    pub special_fusion: bool,
+}
+
+/// The base stats of a demon.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DemonStats {
+    /// The name of the demon.
+    pub demon: String,
+    /// The strength (St).
+    pub strength: i64,
+    /// The magic (Ma).
+    pub magic: i64,
+    /// The vitality (Vi).
+    pub vitality: i64,
+    /// The agility (Ag).
+    pub agility: i64,
+    /// The luck (Lu).
+    pub luck: i64,
+}
+
+/// The namespace of the vocabulary of the RDF dataset.
+pub const VOCABULARY: &str = "https://constraintautomaton.github.io/smt-nocture-db-to-rdf/vocabulary.ttl#";
+
+/// The reaction of a demon to the attacks of an element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Affinity {
+    Weak,
+    Resist,
+    Null,
+    Repel,
+    Drain,
+}
+
+impl Affinity {
+    pub const ALL: [Affinity; 5] = [
+        Affinity::Weak,
+        Affinity::Resist,
+        Affinity::Null,
+        Affinity::Repel,
+        Affinity::Drain,
+    ];
+
+    /// The name of the affinity in the knowledge base.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Affinity::Weak => "weak",
+            Affinity::Resist => "resist",
+            Affinity::Null => "null",
+            Affinity::Repel => "repel",
+            Affinity::Drain => "drain",
+        }
+    }
+
+    /// The IRI of the affinity in the vocabulary.
+    pub fn iri(&self) -> String {
+        let local_name = match self {
+            Affinity::Weak => "Weak",
+            Affinity::Resist => "Resist",
+            Affinity::Null => "Null",
+            Affinity::Repel => "Repel",
+            Affinity::Drain => "Drain",
+        };
+        format!("{VOCABULARY}{local_name}")
+    }
+
+    /// The affinity identified by the IRI.
+    pub fn from_iri(iri: &str) -> Result<Self, ErrorUnknownAffinity> {
+        Self::ALL
+            .into_iter()
+            .find(|affinity| affinity.iri() == iri)
+            .ok_or_else(|| ErrorUnknownAffinity {
+                iri: iri.to_string(),
+            })
+    }
+}
+
+/// The affinity of a demon to an element.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DemonAffinity {
+    /// The name of the demon.
+    pub demon: String,
+    /// The name of the element, like Phys, Fire, Ice, Elec, Force, Expel, Death, Mind, Nerve or Curse.
+    pub element: String,
+    pub affinity: Affinity,
+}
+
+/// The cost of using a skill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SkillCost {
+    /// A cost in MP, for the magic skills.
+    Mp(i64),
+    /// A cost in HP, for the physical skills.
+    Hp(i64),
+}
+
+/// A skill of a demon.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Skill {
+    /// The name of the skill, which is also its identifier.
+    pub name: String,
+    /// The name of the element of the skill.
+    pub element: String,
+    /// The name of the category of the skill, which restricts its inheritance.
+    pub category: String,
+    /// The cost of the skill, which passive skills do not have.
+    pub cost: Option<SkillCost>,
+    /// The power of the skill, which passive and support skills do not have.
+    pub power: Option<i64>,
+    /// The target of the skill.
+    pub target: String,
+}
+
+/// A skill learned by a demon.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LearnedSkill {
+    /// The name of the demon.
+    pub demon: String,
+    /// The name of the skill.
+    pub skill: String,
+    /// The level at which the demon learns the skill, which is its base level for an innate skill.
+    pub level: i64,
+}
+
+/// A category of skills that the demons of a race can inherit through fusion.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InheritanceRule {
+    /// The name of the race.
+    pub race: String,
+    /// The name of the skill category.
+    pub category: String,
+}
+
+/// A Magatama, which the Demi-fiend swallows to gain its stat bonuses, affinities and skills.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Magatama {
+    pub name: String,
+    pub strength: i64,
+    pub magic: i64,
+    pub vitality: i64,
+    pub agility: i64,
+    pub luck: i64,
+    /// The place where the Magatama is obtained.
+    pub location: Option<String>,
+}
+
+/// The affinity of the Demi-fiend to an element while it carries a Magatama.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MagatamaAffinity {
+    /// The name of the Magatama.
+    pub magatama: String,
+    /// The name of the element.
+    pub element: String,
+    pub affinity: Affinity,
+}
+
+/// A skill taught by a Magatama to the Demi-fiend.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MagatamaSkill {
+    /// The name of the Magatama.
+    pub magatama: String,
+    /// The name of the skill.
+    pub skill: String,
+    /// The level of the Demi-fiend at which the Magatama teaches the skill.
+    pub level: i64,
+}
+
+/// The evolution of a demon into another demon once it reaches a level.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Evolution {
+    /// The name of the evolving demon.
+    pub from: String,
+    /// The name of the demon it evolves into.
+    pub to: String,
+    /// The level at which the demon evolves.
+    pub level: i64,
+}
+
+/// An area of the field where a demon can be recruited.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Recruitment {
+    /// The name of the demon.
+    pub demon: String,
+    /// The name of the area.
+    pub area: String,
+    /// The phase of Kagutsuchi during which the demon appears, when it is restricted.
+    pub kagutsuchi_phase: Option<KagutsuchiPhase>,
+}
+
+/// A race of demons.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Race {
+    /// The IRI of the race in the RDF dataset.
+    pub iri: String,
+    /// The name of the race.
+    pub name: String,
+}
+
+/// A rule describing the race resulting from the normal fusion of two races.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RaceFusionRule {
+    /// The name of the first race.
+    pub race1: String,
+    /// The name of the second race.
+    pub race2: String,
+    /// The name of the resulting race.
+    pub result: String,
+}
+
+/// A rule describing the element demon resulting from the fusion of two demons of the same race.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ElementFusionRule {
+    /// The name of the race of the fused demons.
+    pub race: String,
+    /// The name of the resulting element demon.
+    pub element: String,
+}
+
+/// A rule describing how the fusion of a demon with an element demon moves it inside its race.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ElementRankShiftRule {
+    /// The name of the race of the demon.
+    pub race: String,
+    /// The name of the element demon.
+    pub element: String,
+    /// The number of ranks the demon moves up, or down when negative.
+    pub shift: i64,
+}
+
+/// A rule describing a stat boosted by the fusion of a demon with a Mitama.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MitamaFusionRule {
+    /// The name of the Mitama.
+    pub mitama: String,
+    /// The abbreviation of the boosted stat.
+    pub stat: String,
+    /// The number of points added to the stat.
+    pub boost: i64,
+}
+
+/// A phase of Kagutsuchi, the moon of the Vortex World, from 0 (new) to 8 (full).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct KagutsuchiPhase(u8);
+
+impl KagutsuchiPhase {
+    pub const NEW: Self = Self(0);
+    pub const HALF: Self = Self(4);
+    pub const FULL: Self = Self(8);
+
+    /// Every phase of Kagutsuchi in the order of the cycle.
+    pub fn all() -> impl Iterator<Item = Self> {
+        (Self::NEW.0..=Self::FULL.0).map(Self)
+    }
+
+    pub fn new(phase: i64) -> Result<Self, ErrorInvalidKagutsuchiPhase> {
+        u8::try_from(phase)
+            .ok()
+            .filter(|phase| *phase <= Self::FULL.0)
+            .map(Self)
+            .ok_or_else(|| ErrorInvalidKagutsuchiPhase {
+                phase: phase.to_string(),
+            })
+    }
+
+    /// The phase as the number of eighths of Kagutsuchi that are lit.
+    pub fn value(&self) -> u8 {
+        self.0
+    }
+}
+
+impl fmt::Display for KagutsuchiPhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/8", self.0)
+    }
+}
+
+impl FromStr for KagutsuchiPhase {
+    type Err = ErrorInvalidKagutsuchiPhase;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "new" => Ok(Self::NEW),
+            "half" => Ok(Self::HALF),
+            "full" => Ok(Self::FULL),
+            _ => s
+                .parse::<i64>()
+                .map_err(|_| ErrorInvalidKagutsuchiPhase {
+                    phase: s.to_string(),
+                })
+                .and_then(Self::new),
+        }
+    }
+}
+
+/// A recipe to obtain a demon with a special fusion.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SpecialFusionRecipe {
+    /// The IRI of the recipe in the RDF dataset, or the label of its blank node.
+    pub iri: String,
+    /// The name of the resulting demon.
+    pub result: String,
+    /// The names of the demons to fuse, sorted by name, a demon used twice appearing twice.
+    pub ingredients: Vec<String>,
+    /// The phases of Kagutsuchi during one of which the fusion can be performed, sorted, or empty when the fusion
+    /// can be performed during every phase.
+    pub kagutsuchi_phases: Vec<KagutsuchiPhase>,
+    /// The story prerequisites of the fusion, sorted.
+    pub prerequisites: Vec<String>,
+}
+
+/// The demons, races and fusion rules of Shin Megami Tensei III: Nocturne loaded from the RDF dataset.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Model {
+    pub demons: Vec<Demon>,
+    pub demon_stats: Vec<DemonStats>,
+    pub affinities: Vec<DemonAffinity>,
+    pub skills: Vec<Skill>,
+    pub learned_skills: Vec<LearnedSkill>,
+    pub magatamas: Vec<Magatama>,
+    pub magatama_affinities: Vec<MagatamaAffinity>,
+    pub magatama_skills: Vec<MagatamaSkill>,
+    pub inheritance_rules: Vec<InheritanceRule>,
+    pub races: Vec<Race>,
+    pub evolutions: Vec<Evolution>,
+    pub recruitments: Vec<Recruitment>,
+    pub race_fusion_rules: Vec<RaceFusionRule>,
+    pub element_fusion_rules: Vec<ElementFusionRule>,
+    pub element_rank_shift_rules: Vec<ElementRankShiftRule>,
+    pub mitama_fusion_rules: Vec<MitamaFusionRule>,
+    pub special_fusion_recipes: Vec<SpecialFusionRecipe>,
+}
+
+impl Model {
+    /// Load the model from a dataset containing the demons, the races and the fusion rules.
+    pub fn load(dataset: &Dataset) -> Result<Self, Error> {
+        let demons = query_demons(dataset)?;
+        let demon_stats = query_demon_stats(dataset)?;
+        validate_demon_stats(&demons, &demon_stats)?;
+        let affinities = query_affinities(dataset)?;
+        let skills = query_skills(dataset)?;
+        let learned_skills = query_learned_skills(dataset)?;
+        let magatamas = query_magatamas(dataset)?;
+        let magatama_affinities = query_magatama_affinities(dataset)?;
+        let magatama_skills = query_magatama_skills(dataset)?;
+        let inheritance_rules = query_inheritance_rules(dataset)?;
+        let races = query_races(dataset)?;
+        let evolutions = query_evolutions(dataset)?;
+        let recruitments = query_recruitments(dataset)?;
+        let race_fusion_rules = query_race_fusion_rules(dataset)?;
+        let element_fusion_rules = query_element_fusion_rules(dataset)?;
+        let element_rank_shift_rules = query_element_rank_shift_rules(dataset)?;
+        let mitama_fusion_rules = query_mitama_fusion_rules(dataset)?;
+        let special_fusion_recipes = query_special_fusion_recipes(dataset)?;
+
+        Ok(Self {
+            demons,
+            demon_stats,
+            affinities,
+            skills,
+            learned_skills,
+            magatamas,
+            magatama_affinities,
+            magatama_skills,
+            inheritance_rules,
+            races,
+            evolutions,
+            recruitments,
+            race_fusion_rules,
+            element_fusion_rules,
+            element_rank_shift_rules,
+            mitama_fusion_rules,
+            special_fusion_recipes,
+        })
+    }
+
+    /// Find a demon by its name.
+    pub fn demon(&self, name: &str) -> Option<&Demon> {
+        self.demons.iter().find(|demon| demon.name == name)
+    }
+
+    /// The base stats of the demon `name`.
+    pub fn stats(&self, name: &str) -> Option<&DemonStats> {
+        self.demon_stats.iter().find(|stats| stats.demon == name)
+    }
+
+    /// The affinities of the demon `name` to the elements it does not take normal damage from.
+    pub fn affinities(&self, name: &str) -> Vec<&DemonAffinity> {
+        self.affinities
+            .iter()
+            .filter(|affinity| affinity.demon == name)
+            .collect()
+    }
+
+    /// Find a skill by its name.
+    pub fn skill(&self, name: &str) -> Option<&Skill> {
+        self.skills.iter().find(|skill| skill.name == name)
+    }
+
+    /// The skills the demon `name` has learned at the level `level`, sorted by learning level then by name.
+    pub fn skills_at_level(&self, name: &str, level: i64) -> Vec<&Skill> {
+        let mut learned_skills: Vec<&LearnedSkill> = self
+            .learned_skills
+            .iter()
+            .filter(|learned| learned.demon == name && learned.level <= level)
+            .collect();
+        learned_skills.sort_by(|a, b| a.level.cmp(&b.level).then(a.skill.cmp(&b.skill)));
+        learned_skills
+            .into_iter()
+            .filter_map(|learned| self.skill(&learned.skill))
+            .collect()
+    }
+
+    /// Find a Magatama by its name.
+    pub fn magatama(&self, name: &str) -> Option<&Magatama> {
+        self.magatamas.iter().find(|magatama| magatama.name == name)
+    }
+
+    /// The skills the Magatama `name` teaches, sorted by level then by name.
+    pub fn magatama_skills(&self, name: &str) -> Vec<&MagatamaSkill> {
+        let mut skills: Vec<&MagatamaSkill> = self
+            .magatama_skills
+            .iter()
+            .filter(|skill| skill.magatama == name)
+            .collect();
+        skills.sort_by(|a, b| a.level.cmp(&b.level).then(a.skill.cmp(&b.skill)));
+        skills
+    }
+
+    /// The evolution of the demon `name`, if it evolves.
+    pub fn evolution(&self, name: &str) -> Option<&Evolution> {
+        self.evolutions.iter().find(|evolution| evolution.from == name)
+    }
+
+    /// The demons that can be recruited in the area `area`.
+    pub fn recruitable_demons(&self, area: &str) -> Vec<&Demon> {
+        let mut demons: Vec<&Demon> = self
+            .recruitments
+            .iter()
+            .filter(|recruitment| recruitment.area == area)
+            .filter_map(|recruitment| self.demon(&recruitment.demon))
+            .collect();
+        demons.sort_by(|a, b| a.name.cmp(&b.name));
+        demons.dedup();
+        demons
+    }
+
+    /// The special fusion recipes producing the demon `name`.
+    pub fn special_fusion_recipes(&self, name: &str) -> Vec<&SpecialFusionRecipe> {
+        self.special_fusion_recipes
+            .iter()
+            .filter(|recipe| recipe.result == name)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod model_test {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn should_load_a_model() -> Result<(), Error> {
+        let demon_file_path = PathBuf::from("./test_files/test_valid_demon.ttl");
+        let stats_file_path = PathBuf::from("./test_files/test_valid_demon_stats.ttl");
+        let race_file_path = PathBuf::from("./test_files/test_valid_race.ttl");
+        let rule_file_path = PathBuf::from("./test_files/test_valid_rule.ttl");
+        let dataset = Dataset::load(&[
+            &demon_file_path,
+            &stats_file_path,
+            &race_file_path,
+            &rule_file_path,
+        ])?;
+
+        let model = Model::load(&dataset)?;
+
+        assert_eq!(model.demons.len(), 2);
+        assert_eq!(model.races.len(), 6);
+        assert_eq!(model.race_fusion_rules.len(), 2);
+        assert!(model.evolutions.is_empty());
+        assert!(model.recruitments.is_empty());
+        assert!(model.element_fusion_rules.is_empty());
+        assert!(model.element_rank_shift_rules.is_empty());
+        assert!(model.mitama_fusion_rules.is_empty());
+        assert!(model.special_fusion_recipes.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn should_load_the_special_fusion_recipes_of_a_demon() -> Result<(), Error> {
+        let demon_file_path = PathBuf::from("./test_files/test_valid_demon.ttl");
+        let stats_file_path = PathBuf::from("./test_files/test_valid_demon_stats.ttl");
+        let special_fusion_file_path =
+            PathBuf::from("./test_files/test_valid_special_fusion.ttl");
+        let dataset = Dataset::load(&[
+            &demon_file_path,
+            &stats_file_path,
+            &special_fusion_file_path,
+        ])?;
+
+        let model = Model::load(&dataset)?;
+
+        assert_eq!(model.special_fusion_recipes.len(), 2);
+        assert_eq!(model.special_fusion_recipes("Abaddon").len(), 1);
+        assert_eq!(
+            model.special_fusion_recipes("Abaddon")[0].ingredients,
+            vec!["Aeros".to_string(), "Hope".to_string(), "Matador".to_string()]
+        );
+        assert!(model.special_fusion_recipes("Aeros").is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn should_find_the_evolution_of_a_demon() -> Result<(), Error> {
+        let demon_file_path = PathBuf::from("./test_files/test_valid_demon.ttl");
+        let stats_file_path = PathBuf::from("./test_files/test_valid_demon_stats.ttl");
+        let evolution_file_path = PathBuf::from("./test_files/test_valid_evolution.ttl");
+        let dataset = Dataset::load(&[&demon_file_path, &stats_file_path, &evolution_file_path])?;
+
+        let model = Model::load(&dataset)?;
+
+        assert_eq!(
+            model.evolution("Aeros"),
+            Some(&Evolution {
+                from: "Aeros".to_string(),
+                to: "Abaddon".to_string(),
+                level: 40,
+            })
+        );
+        assert_eq!(model.evolution("Abaddon"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn should_find_the_recruitable_demons_of_an_area() -> Result<(), Error> {
+        let demon_file_path = PathBuf::from("./test_files/test_valid_demon.ttl");
+        let stats_file_path = PathBuf::from("./test_files/test_valid_demon_stats.ttl");
+        let race_file_path = PathBuf::from("./test_files/test_valid_race.ttl");
+        let recruitment_file_path = PathBuf::from("./test_files/test_valid_recruitment.ttl");
+        let dataset = Dataset::load(&[
+            &demon_file_path,
+            &stats_file_path,
+            &race_file_path,
+            &recruitment_file_path,
+        ])?;
+
+        let model = Model::load(&dataset)?;
+
+        assert_eq!(
+            model
+                .recruitable_demons("Ginza")
+                .iter()
+                .map(|demon| demon.name.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["Abaddon", "Aeros"]
+        );
+        assert_eq!(
+            model
+                .recruitable_demons("Shinjuku Medical Center")
+                .iter()
+                .map(|demon| demon.name.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["Aeros"]
+        );
+        assert!(model.recruitable_demons("Ikebukuro").is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn should_load_the_stats_of_the_demons() -> Result<(), Error> {
+        let demon_file_path = PathBuf::from("./test_files/test_valid_demon.ttl");
+        let race_file_path = PathBuf::from("./test_files/test_valid_race.ttl");
+        let stats_file_path = PathBuf::from("./test_files/test_valid_demon_stats.ttl");
+        let dataset = Dataset::load(&[&demon_file_path, &race_file_path, &stats_file_path])?;
+
+        let model = Model::load(&dataset)?;
+
+        assert_eq!(model.demon_stats.len(), 2);
+        assert_eq!(model.stats("Aeros").map(|stats| stats.magic), Some(9));
+        assert_eq!(model.stats("Pixie"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_an_error_given_a_demon_without_stats() -> Result<(), Error> {
+        let demon_file_path = PathBuf::from("./test_files/test_valid_demon.ttl");
+        let race_file_path = PathBuf::from("./test_files/test_valid_race.ttl");
+        let stats_file_path = PathBuf::from("./test_files/test_partial_demon_stats.ttl");
+        let dataset = Dataset::load(&[&demon_file_path, &race_file_path, &stats_file_path])?;
+
+        let model = Model::load(&dataset);
+
+        assert_eq!(
+            model.err().map(|error| error.to_string()),
+            Some("the demon 'Aeros' has no stat block".to_string())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_an_error_given_a_demon_with_two_values_for_a_stat() -> Result<(), Error> {
+        let demon_file_path = PathBuf::from("./test_files/test_valid_demon.ttl");
+        let race_file_path = PathBuf::from("./test_files/test_valid_race.ttl");
+        let stats_file_path = PathBuf::from("./test_files/test_ambiguous_demon_stats.ttl");
+        let dataset = Dataset::load(&[&demon_file_path, &race_file_path, &stats_file_path])?;
+
+        let model = Model::load(&dataset);
+
+        assert_eq!(
+            model.err().map(|error| error.to_string()),
+            Some("the demon 'Abaddon' has more than one value for the stat 'strength'".to_string())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_load_the_affinities_of_a_demon() -> Result<(), Error> {
+        let demon_file_path = PathBuf::from("./test_files/test_valid_demon.ttl");
+        let stats_file_path = PathBuf::from("./test_files/test_valid_demon_stats.ttl");
+        let affinity_file_path = PathBuf::from("./test_files/test_valid_affinity.ttl");
+        let dataset = Dataset::load(&[&demon_file_path, &stats_file_path, &affinity_file_path])?;
+
+        let model = Model::load(&dataset)?;
+
+        assert_eq!(model.affinities.len(), 3);
+        assert_eq!(
+            model
+                .affinities("Aeros")
+                .iter()
+                .map(|affinity| affinity.affinity)
+                .collect::<Vec<Affinity>>(),
+            vec![Affinity::Weak]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_list_the_skills_of_a_demon_at_a_level() -> Result<(), Error> {
+        let demon_file_path = PathBuf::from("./test_files/test_valid_demon.ttl");
+        let stats_file_path = PathBuf::from("./test_files/test_valid_demon_stats.ttl");
+        let skill_file_path = PathBuf::from("./test_files/test_valid_skill.ttl");
+        let dataset = Dataset::load(&[&demon_file_path, &stats_file_path, &skill_file_path])?;
+
+        let model = Model::load(&dataset)?;
+        let skill_names = |level| -> Vec<&str> {
+            model
+                .skills_at_level("Aeros", level)
+                .iter()
+                .map(|skill| skill.name.as_str())
+                .collect()
+        };
+
+        assert!(skill_names(10).is_empty());
+        assert_eq!(skill_names(11), vec!["Zan"]);
+        assert_eq!(skill_names(99), vec!["Zan", "Tarunda", "Force Boost"]);
+        assert!(model.skills_at_level("Pixie", 99).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn should_load_the_magatamas_and_their_skills() -> Result<(), Error> {
+        let magatama_file_path = PathBuf::from("./test_files/test_valid_magatama.ttl");
+        let skill_file_path = PathBuf::from("./test_files/test_valid_skill.ttl");
+        let dataset = Dataset::load(&[&magatama_file_path, &skill_file_path])?;
+
+        let model = Model::load(&dataset)?;
+
+        assert_eq!(
+            model.magatama("Marogareh").map(|magatama| magatama.strength),
+            Some(2)
+        );
+        assert!(model.magatama("Pixie").is_none());
+        assert_eq!(
+            model
+                .magatama_skills("Wadatsumi")
+                .iter()
+                .map(|skill| skill.skill.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["Tarunda"]
+        );
+        assert_eq!(model.magatama_affinities.len(), 2);
+        assert!(model.demons.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn should_load_an_empty_model_given_an_empty_dataset() -> Result<(), Error> {
+        let dataset = Dataset::load(&[])?;
+
+        let model = Model::load(&dataset)?;
+
+        assert_eq!(model, Model::default());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod affinity_test {
+    use super::*;
+
+    #[test]
+    fn should_find_an_affinity_from_its_iri() -> Result<(), ErrorUnknownAffinity> {
+        for affinity in Affinity::ALL {
+            assert_eq!(Affinity::from_iri(&affinity.iri())?, affinity);
+        }
+        assert_eq!(
+            Affinity::from_iri(&format!("{VOCABULARY}Drain"))?,
+            Affinity::Drain
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_an_error_given_an_unknown_iri() {
+        let resp = Affinity::from_iri("http://example.com/Absorb");
+
+        assert_eq!(
+            resp.err().map(|error| error.to_string()),
+            Some("unknown affinity 'http://example.com/Absorb'".to_string())
+        );
+    }
+}
+
+#[cfg(test)]
+mod kagutsuchi_phase_test {
+    use super::*;
+
+    #[test]
+    fn should_create_a_phase_from_0_to_8() -> Result<(), ErrorInvalidKagutsuchiPhase> {
+        assert_eq!(KagutsuchiPhase::new(0)?, KagutsuchiPhase::NEW);
+        assert_eq!(KagutsuchiPhase::new(8)?, KagutsuchiPhase::FULL);
+        assert!(KagutsuchiPhase::new(9).is_err());
+        assert!(KagutsuchiPhase::new(-1).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn should_parse_a_phase() -> Result<(), ErrorInvalidKagutsuchiPhase> {
+        assert_eq!("full".parse::<KagutsuchiPhase>()?, KagutsuchiPhase::FULL);
+        assert_eq!("half".parse::<KagutsuchiPhase>()?, KagutsuchiPhase::HALF);
+        assert_eq!("3".parse::<KagutsuchiPhase>()?.value(), 3);
+        assert_eq!(
+            "crescent".parse::<KagutsuchiPhase>().map_err(|error| error.to_string()),
+            Err("invalid phase of Kagutsuchi 'crescent', expected an integer from 0 to 8, 'new', 'half' or 'full'".to_string())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_every_phase() {
+        let phases: Vec<KagutsuchiPhase> = KagutsuchiPhase::all().collect();
+
+        assert_eq!(phases.len(), 9);
+        assert_eq!(phases.first(), Some(&KagutsuchiPhase::NEW));
+        assert_eq!(phases.last(), Some(&KagutsuchiPhase::FULL));
+    }
+}
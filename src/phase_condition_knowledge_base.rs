@@ -0,0 +1,127 @@
+// smt-nocturne-prolog-kb-generator: A generates of a Prolog knowledge base
+// to describe demons, their fusion, and fusion mechanics for the game 
+// Shin Megami Tensei III: Nocturne.
+// Copyright (C) 2025  Bryan-Elliott Tam
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::fusion::FusionKind;
+use crate::model::*;
+use crate::prolog::*;
+
+/// create a prolog knowledge based containing the phases of Kagutsuchi during which each kind of fusion
+/// and each special fusion of Shin Megami Tensei Nocture demons can be performed.
+pub fn create_prolog_phase_condition_knowledge_base(
+    model: &Model,
+    name_representation: NameRepresentation,
+) -> Vec<PrologTerm> {
+    let mut facts = Vec::new();
+    for kind in FusionKind::ALL {
+        for phase in KagutsuchiPhase::all().filter(|phase| kind.is_possible_at(*phase)) {
+            facts.push(generate_a_prolog_fact(
+                PrologTerm::Atom(kind.name().to_string()),
+                phase,
+            ));
+        }
+    }
+
+    let mut results: Vec<&str> = model
+        .special_fusion_recipes
+        .iter()
+        .map(|recipe| recipe.result.as_str())
+        .collect();
+    results.dedup();
+    for result in results {
+        let recipes = model.special_fusion_recipes(result);
+        for phase in KagutsuchiPhase::all().filter(|phase| {
+            recipes.iter().any(|recipe| {
+                recipe.kagutsuchi_phases.is_empty() || recipe.kagutsuchi_phases.contains(phase)
+            })
+        }) {
+            let kind =
+                PrologTerm::compound("special_fusion", vec![name_representation.term(result)]);
+            facts.push(generate_a_prolog_fact(kind, phase));
+        }
+    }
+    facts
+}
+
+fn generate_a_prolog_fact(kind: PrologTerm, phase: KagutsuchiPhase) -> PrologTerm {
+    PrologTerm::compound(
+        "phase_condition",
+        vec![kind, PrologTerm::Integer(phase.value().into())],
+    )
+}
+
+#[cfg(test)]
+mod create_prolog_phase_condition_knowledge_base_test {
+    use super::*;
+
+    fn recipe(result: &str, kagutsuchi_phases: Vec<KagutsuchiPhase>) -> SpecialFusionRecipe {
+        SpecialFusionRecipe {
+            iri: format!("http://example.com/{result}"),
+            result: result.to_string(),
+            ingredients: vec![],
+            kagutsuchi_phases,
+            prerequisites: vec![],
+        }
+    }
+
+    #[test]
+    fn should_describe_every_phase_of_the_fusions() {
+        let resp = create_prolog_phase_condition_knowledge_base(
+            &Model::default(),
+            NameRepresentation::Atom,
+        );
+        let facts: Vec<String> = resp.iter().map(PrologTerm::to_fact).collect();
+
+        assert_eq!(facts.len(), 19);
+        assert!(facts.contains(&"phase_condition(normal_fusion, 0).".to_string()));
+        assert!(facts.contains(&"phase_condition(mitama_fusion, 8).".to_string()));
+        assert!(facts.contains(&"phase_condition(sacrificial_fusion, 8).".to_string()));
+        assert!(!facts.contains(&"phase_condition(sacrificial_fusion, 7).".to_string()));
+    }
+
+    #[test]
+    fn should_describe_the_phases_of_the_special_fusions() {
+        let model = Model {
+            special_fusion_recipes: vec![
+                recipe("Abaddon", vec![KagutsuchiPhase::NEW, KagutsuchiPhase::FULL]),
+                recipe("Hope", vec![]),
+            ],
+            ..Model::default()
+        };
+
+        let resp = create_prolog_phase_condition_knowledge_base(&model, NameRepresentation::Atom);
+        let special_facts: Vec<String> = resp
+            .iter()
+            .map(PrologTerm::to_fact)
+            .filter(|fact| fact.starts_with("phase_condition(special_fusion("))
+            .collect();
+
+        assert_eq!(special_facts.len(), 11);
+        assert_eq!(
+            special_facts[0],
+            "phase_condition(special_fusion('Abaddon'), 0).".to_string()
+        );
+        assert_eq!(
+            special_facts[1],
+            "phase_condition(special_fusion('Abaddon'), 8).".to_string()
+        );
+        assert_eq!(
+            special_facts[2],
+            "phase_condition(special_fusion('Hope'), 0).".to_string()
+        );
+    }
+}
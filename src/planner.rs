@@ -0,0 +1,1454 @@
+// smt-nocturne-prolog-kb-generator: A generates of a Prolog knowledge base
+// to describe demons, their fusion, and fusion mechanics for the game 
+// Shin Megami Tensei III: Nocturne.
+// Copyright (C) 2025  Bryan-Elliott Tam
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::demon_knowledge_base;
+use crate::error::{ErrorPartyTooLarge, ErrorUnboundedPlan, ErrorUnsupportedPurchase};
+use crate::fusion::*;
+use crate::model::*;
+use crate::prolog::*;
+use anyhow::Error;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+/// The maximum number of demons in the party.
+pub const MAX_PARTY_SIZE: usize = 12;
+
+/// A demon of the party with the skills it carries.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PartyDemon<'a> {
+    pub demon: &'a Demon,
+    /// The skills of the demon, sorted by name.
+    pub skills: Vec<&'a Skill>,
+}
+
+impl<'a> PartyDemon<'a> {
+    pub fn new(demon: &'a Demon, mut skills: Vec<&'a Skill>) -> Self {
+        skills.sort_by(|a, b| a.name.cmp(&b.name));
+        skills.dedup_by(|a, b| a.name == b.name);
+        Self { demon, skills }
+    }
+
+    fn key(&self) -> (&str, Vec<&str>) {
+        (
+            self.demon.name.as_str(),
+            self.skills
+                .iter()
+                .map(|skill| skill.name.as_str())
+                .collect(),
+        )
+    }
+}
+
+/// A party of demons, sorted by name then by skills so that the same multiset of demons is a single state.
+///
+/// The demons only carry the skills required by the goal of the search, the other skills not changing the plan.
+type Party<'a> = Vec<PartyDemon<'a>>;
+
+/// An action of a plan changing the party.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlanAction<'a> {
+    /// The normal fusion of two demons of the party.
+    NormalFusion(NormalFusion<'a>),
+    /// The normal fusion of two demons of the party sacrificing a third demon of the party.
+    SacrificialFusion(SacrificialFusion<'a>),
+    /// The purchase of a demon from the compendium.
+    Purchase(&'a Demon),
+}
+
+/// A plan to obtain a demon.
+///
+/// The Macca cost of the purchases is not supported, since the pricing of the compendium is not in the dataset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan<'a> {
+    pub actions: Vec<PlanAction<'a>>,
+}
+
+/// The result of the search of a demon with skills.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SkillPlan<'a> {
+    /// The shortest plan producing the demon with the skills.
+    Reachable(Plan<'a>),
+    /// The proof that no plan produces the demon with the skills.
+    Unreachable(Unreachability<'a>),
+}
+
+/// The reason why no plan produces a demon with skills.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Unreachability<'a> {
+    /// No demon of the party carries the skill and no demon learns it at its base level.
+    UnavailableSkill(&'a Skill),
+    /// The race of the demon cannot inherit the skill and the demon does not learn it at its base level.
+    UninheritableSkill(&'a Skill),
+    /// No sequence of normal fusions of the party and the compendium produces the demon.
+    UnreachableDemon,
+    /// Every party reachable within the maximum number of steps was explored without producing the demon with the
+    /// skills.
+    Exhausted { explored_parties: usize },
+}
+
+/// The planner of the actions to obtain a demon described in the model of the README.
+pub struct FusionPlanner<'a> {
+    model: &'a Model,
+    engine: FusionEngine<'a>,
+    /// The demons that can be purchased from the compendium.
+    compendium: Vec<&'a Demon>,
+    /// The maximum number of actions of a plan.
+    max_steps: Option<usize>,
+}
+
+impl<'a> FusionPlanner<'a> {
+    pub fn new(model: &'a Model) -> Self {
+        Self {
+            model,
+            engine: FusionEngine::new(model),
+            compendium: Vec::new(),
+            max_steps: None,
+        }
+    }
+
+    /// Allow the purchase of the demons registered in the compendium.
+    pub fn with_compendium(mut self, compendium: &[&'a Demon]) -> Self {
+        self.compendium = compendium.to_vec();
+        self
+    }
+
+    /// Limit the number of actions of a plan.
+    pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = Some(max_steps);
+        self
+    }
+
+    /// Restrict the fusions to the ones possible during the phase of Kagutsuchi.
+    pub fn with_phase(mut self, phase: KagutsuchiPhase) -> Self {
+        self.engine = self.engine.with_phase(phase);
+        self
+    }
+
+    /// The shortest sequence of actions from the party `party` to a party containing the demon `target`.
+    ///
+    /// Return `None` when no sequence of actions within the maximum number of steps produces the demon, and an
+    /// error when the compendium can be bought from without a maximum number of steps.
+    pub fn shortest_plan(
+        &self,
+        party: &[&'a Demon],
+        target: &Demon,
+    ) -> Result<Option<Plan<'a>>, Error> {
+        Self::check_party_size(party.len())?;
+        let party: Vec<PartyDemon<'a>> = party
+            .iter()
+            .map(|demon| PartyDemon::new(demon, Vec::new()))
+            .collect();
+        let ingredients = self.ingredients(self.purchasable(&party), &[], target);
+        if !ingredients.contains(target.name.as_str()) {
+            return Ok(None);
+        }
+        let purchases = self.purchases(&ingredients, &[])?;
+        Ok(self.search(&party, target, &[], &purchases).0)
+    }
+
+    /// The shortest sequence of actions from the party `party` to a party containing the demon `target` carrying
+    /// the skills `skills`.
+    ///
+    /// The fused demons carry the skills they learn at their base level and the skills they inherit from their
+    /// ingredients, and the purchased demons the skills they learn at their base level.
+    /// When no such sequence exists, return the proof that the demon with the skills is unreachable, and an
+    /// error when the compendium can be bought from without a maximum number of steps.
+    pub fn shortest_plan_with_skills(
+        &self,
+        party: &[PartyDemon<'a>],
+        target: &Demon,
+        skills: &[&'a Skill],
+    ) -> Result<SkillPlan<'a>, Error> {
+        Self::check_party_size(party.len())?;
+        let mut goal = skills.to_vec();
+        goal.sort_by(|a, b| a.name.cmp(&b.name));
+        goal.dedup_by(|a, b| a.name == b.name);
+        let ingredients = self.ingredients(self.purchasable(party), &[], target);
+        if !ingredients.contains(target.name.as_str()) {
+            return Ok(SkillPlan::Unreachable(Unreachability::UnreachableDemon));
+        }
+        if let Some(unreachability) = self.unavailable_skill(party, target, &goal) {
+            return Ok(SkillPlan::Unreachable(unreachability));
+        }
+        let purchases = self.purchases(&ingredients, &goal)?;
+        Ok(match self.search(party, target, &goal, &purchases) {
+            (Some(plan), _) => SkillPlan::Reachable(plan),
+            (None, explored_parties) => {
+                SkillPlan::Unreachable(Unreachability::Exhausted { explored_parties })
+            }
+        })
+    }
+
+    /// The demon `demon` carrying the skills it learns at its base level.
+    pub fn innate_party_demon(&self, demon: &'a Demon) -> PartyDemon<'a> {
+        PartyDemon::new(
+            demon,
+            self.model.skills_at_level(&demon.name, demon.base_level),
+        )
+    }
+
+    /// The first skill of `skills` that no plan can give to the demon `target`, without searching the plans.
+    fn unavailable_skill(
+        &self,
+        party: &[PartyDemon<'a>],
+        target: &Demon,
+        skills: &[&'a Skill],
+    ) -> Option<Unreachability<'a>> {
+        let carries = |member: &PartyDemon, skill: &Skill| {
+            member.skills.iter().any(|other| other.name == skill.name)
+        };
+        let learns = |demon: &Demon, skill: &Skill| {
+            self.model
+                .skills_at_level(&demon.name, demon.base_level)
+                .iter()
+                .any(|other| other.name == skill.name)
+        };
+        for skill in skills {
+            let available = party.iter().any(|member| carries(member, skill))
+                || self.model.demons.iter().any(|demon| learns(demon, skill));
+            if !available {
+                return Some(Unreachability::UnavailableSkill(skill));
+            }
+        }
+        for skill in skills {
+            let carried_by_target = party
+                .iter()
+                .any(|member| member.demon.name == target.name && carries(member, skill));
+            let inheritable = self
+                .model
+                .inheritance_rules
+                .iter()
+                .any(|rule| rule.race == target.race && rule.category == skill.category);
+            if !carried_by_target && !inheritable && !learns(target, skill) {
+                return Some(Unreachability::UninheritableSkill(skill));
+            }
+        }
+        None
+    }
+
+    /// The demons of the party `party` and of the compendium.
+    fn purchasable(&self, party: &[PartyDemon<'a>]) -> Vec<&'a Demon> {
+        party
+            .iter()
+            .map(|member| member.demon)
+            .chain(self.compendium.iter().copied())
+            .collect()
+    }
+
+    /// The demons `demons`, and the demons they can produce with a sequence of normal fusions and of the special
+    /// fusions `recipes`, that can feed such a sequence producing the demon `target`, including the demon itself
+    /// when it can be obtained.
+    ///
+    /// A sacrificial fusion produces the same demon as the normal fusion of its ingredients, and a demon can be
+    /// fused with a copy of itself, so no sequence of fusions produces the demon when it is not part of the
+    /// ingredients.
+    fn ingredients(
+        &self,
+        demons: Vec<&'a Demon>,
+        recipes: &[&'a SpecialFusionRecipe],
+        target: &Demon,
+    ) -> HashSet<&'a str> {
+        let mut obtainable: Vec<&'a Demon> = Vec::new();
+        let mut names: HashSet<&'a str> = HashSet::new();
+        let mut producers: HashMap<&'a str, Vec<(&'a str, &'a str)>> = HashMap::new();
+        let mut pending = demons;
+        while !pending.is_empty() {
+            while let Some(demon) = pending.pop() {
+                if !names.insert(demon.name.as_str()) {
+                    continue;
+                }
+                obtainable.push(demon);
+                for other in &obtainable {
+                    if let Some(result) = self.engine.normal_fusion(demon, other) {
+                        producers
+                            .entry(result.name.as_str())
+                            .or_default()
+                            .push((demon.name.as_str(), other.name.as_str()));
+                        if !names.contains(result.name.as_str()) {
+                            pending.push(result);
+                        }
+                    }
+                }
+            }
+            for recipe in recipes {
+                if !names.contains(recipe.result.as_str())
+                    && recipe
+                        .ingredients
+                        .iter()
+                        .all(|ingredient| names.contains(ingredient.as_str()))
+                {
+                    pending.extend(self.model.demon(&recipe.result));
+                }
+            }
+        }
+
+        let mut ingredients: HashSet<&'a str> = HashSet::new();
+        let mut pending: Vec<&'a str> = names
+            .iter()
+            .copied()
+            .filter(|name| *name == target.name)
+            .collect();
+        while let Some(name) = pending.pop() {
+            if !ingredients.insert(name) {
+                continue;
+            }
+            for (demon1, demon2) in producers.get(name).into_iter().flatten() {
+                pending.extend([*demon1, *demon2]);
+            }
+            for recipe in recipes.iter().filter(|recipe| recipe.result == name) {
+                if recipe
+                    .ingredients
+                    .iter()
+                    .all(|ingredient| names.contains(ingredient.as_str()))
+                {
+                    pending.extend(recipe.ingredients.iter().map(String::as_str));
+                }
+            }
+        }
+        ingredients
+    }
+
+    /// The demons of the compendium worth buying: the ingredients `ingredients` and the demons learning a skill of
+    /// the goal `goal` at their base level.
+    ///
+    /// Return an error when there is such a demon without a maximum number of steps, since the purchases would make
+    /// the parties to explore grow without bound.
+    fn purchases(
+        &self,
+        ingredients: &HashSet<&'a str>,
+        goal: &[&'a Skill],
+    ) -> Result<Vec<&'a Demon>, ErrorUnboundedPlan> {
+        let purchases: Vec<&'a Demon> = self
+            .compendium
+            .iter()
+            .copied()
+            .filter(|demon| {
+                ingredients.contains(demon.name.as_str())
+                    || !Self::required(
+                        self.model.skills_at_level(&demon.name, demon.base_level),
+                        goal,
+                    )
+                    .is_empty()
+            })
+            .collect();
+        if !purchases.is_empty() && self.max_steps.is_none() {
+            return Err(ErrorUnboundedPlan {
+                compendium: purchases.len(),
+            });
+        }
+        Ok(purchases)
+    }
+
+    /// The shortest plan to a party containing the demon `target` carrying the skills `goal`, buying the demons
+    /// `purchases`, with the number of explored parties.
+    fn search(
+        &self,
+        party: &[PartyDemon<'a>],
+        target: &Demon,
+        goal: &[&'a Skill],
+        purchases: &[&'a Demon],
+    ) -> (Option<Plan<'a>>, usize) {
+        let mut start: Party<'a> = party
+            .iter()
+            .map(|member| {
+                PartyDemon::new(member.demon, Self::required(member.skills.clone(), goal))
+            })
+            .collect();
+        start.sort_by(|a, b| a.key().cmp(&b.key()));
+
+        let mut parties: Vec<Party<'a>> = vec![start.clone()];
+        let mut costs: HashMap<Party<'a>, usize> = HashMap::from([(start, 0)]);
+        let mut previous: HashMap<Party<'a>, (Party<'a>, PlanAction<'a>)> = HashMap::new();
+        let mut queue = BinaryHeap::from([Reverse((0, 0))]);
+        let mut explored_parties = 0;
+        while let Some(Reverse((steps, index))) = queue.pop() {
+            let party = parties[index].clone();
+            if costs.get(&party) < Some(&steps) {
+                continue;
+            }
+            explored_parties += 1;
+            if party
+                .iter()
+                .any(|member| member.demon.name == target.name && member.skills.len() == goal.len())
+            {
+                return (
+                    Some(Plan {
+                        actions: Self::actions_to(&previous, party),
+                    }),
+                    explored_parties,
+                );
+            }
+            for (next_party, action) in self.next_parties(&party, goal, purchases) {
+                let cost = steps + 1;
+                if self.max_steps.is_some_and(|max_steps| cost > max_steps) {
+                    continue;
+                }
+                if costs.get(&next_party).is_some_and(|best| *best <= cost) {
+                    continue;
+                }
+                costs.insert(next_party.clone(), cost);
+                previous.insert(next_party.clone(), (party.clone(), action));
+                parties.push(next_party);
+                queue.push(Reverse((cost, parties.len() - 1)));
+            }
+        }
+        (None, explored_parties)
+    }
+
+    fn check_party_size(size: usize) -> Result<(), ErrorPartyTooLarge> {
+        if size > MAX_PARTY_SIZE {
+            return Err(ErrorPartyTooLarge {
+                size,
+                max_size: MAX_PARTY_SIZE,
+            });
+        }
+        Ok(())
+    }
+
+    /// The skills of `skills` required by the goal `goal`.
+    fn required(skills: Vec<&'a Skill>, goal: &[&'a Skill]) -> Vec<&'a Skill> {
+        skills
+            .into_iter()
+            .filter(|skill| goal.iter().any(|required| required.name == skill.name))
+            .collect()
+    }
+
+    /// The demon `demon` carrying the skills of the goal `goal` it learns at its base level and the skills
+    /// `inherited`.
+    fn party_demon(
+        &self,
+        demon: &'a Demon,
+        inherited: Vec<&'a Skill>,
+        goal: &[&'a Skill],
+    ) -> PartyDemon<'a> {
+        if goal.is_empty() {
+            return PartyDemon::new(demon, Vec::new());
+        }
+        let mut skills = self.model.skills_at_level(&demon.name, demon.base_level);
+        skills.extend(inherited);
+        PartyDemon::new(demon, Self::required(skills, goal))
+    }
+
+    /// The demon produced by the fusion of the demons `member1` and `member2` with the sacrificed demon
+    /// `sacrifice`, carrying the skills of the goal `goal` it learns or inherits.
+    fn fused_party_demon(
+        &self,
+        result: &'a Demon,
+        member1: &PartyDemon<'a>,
+        member2: &PartyDemon<'a>,
+        sacrifice: Option<&PartyDemon<'a>>,
+        goal: &[&'a Skill],
+    ) -> PartyDemon<'a> {
+        if goal.is_empty() {
+            return PartyDemon::new(result, Vec::new());
+        }
+        let skills2: Vec<&'a Skill> = member2
+            .skills
+            .iter()
+            .chain(
+                sacrifice
+                    .iter()
+                    .flat_map(|sacrifice| sacrifice.skills.iter()),
+            )
+            .copied()
+            .collect();
+        let inherited = self
+            .engine
+            .inheritable_skills(member1.demon, &member1.skills, member2.demon, &skills2)
+            .unwrap_or_default();
+        self.party_demon(result, inherited, goal)
+    }
+
+    /// The demons recruitable in the area `area` during the phase of Kagutsuchi that can feed a sequence of
+    /// normal or special fusions producing the demon `target`, the sequence only fusing the recruitable demons,
+    /// the demons of the party `party` and the demons they produce.
+    pub fn recruitable_ingredients(
+        &self,
+        area: &str,
+        party: &[&'a Demon],
+        target: &Demon,
+    ) -> Vec<&'a Demon> {
+        let phase = self.engine.phase();
+        let mut recruitable: Vec<&'a Demon> = self
+            .model
+            .recruitments
+            .iter()
+            .filter(|recruitment| recruitment.area == area)
+            .filter(|recruitment| match (recruitment.kagutsuchi_phase, phase) {
+                (Some(required), Some(phase)) => required == phase,
+                _ => true,
+            })
+            .filter_map(|recruitment| self.model.demon(&recruitment.demon))
+            .collect();
+        recruitable.sort_by(|a, b| a.name.cmp(&b.name));
+        recruitable.dedup();
+
+        let mut results: Vec<&'a str> = self
+            .model
+            .special_fusion_recipes
+            .iter()
+            .map(|recipe| recipe.result.as_str())
+            .collect();
+        results.sort();
+        results.dedup();
+        let recipes: Vec<&'a SpecialFusionRecipe> = results
+            .into_iter()
+            .flat_map(|result| self.engine.special_fusion_recipes(result))
+            .collect();
+
+        let demons = recruitable.iter().chain(party).copied().collect();
+        let ingredients = self.ingredients(demons, &recipes, target);
+        recruitable
+            .into_iter()
+            .filter(|demon| ingredients.contains(demon.name.as_str()))
+            .collect()
+    }
+
+    /// The parties obtained with a fusion or the purchase of a demon of `purchases` from the party, the demons
+    /// carrying the skills of the goal `goal`.
+    ///
+    /// A party never holds a demon twice, so neither a fusion nor a purchase can add a demon already in the party.
+    fn next_parties(
+        &self,
+        party: &Party<'a>,
+        goal: &[&'a Skill],
+        purchases: &[&'a Demon],
+    ) -> Vec<(Party<'a>, PlanAction<'a>)> {
+        let mut next_parties = Vec::new();
+        for i in 0..party.len() {
+            for j in i + 1..party.len() {
+                let Some(result) = self.engine.normal_fusion(party[i].demon, party[j].demon) else {
+                    continue;
+                };
+                let next_party: Party<'a> = party
+                    .iter()
+                    .enumerate()
+                    .filter(|(k, _)| *k != i && *k != j)
+                    .map(|(_, member)| member.clone())
+                    .collect();
+                if !Self::contains(&next_party, result) {
+                    next_parties.push((
+                        Self::with_demon(
+                            next_party.clone(),
+                            self.fused_party_demon(result, &party[i], &party[j], None, goal),
+                        ),
+                        PlanAction::NormalFusion(NormalFusion {
+                            demon1: party[i].demon,
+                            demon2: party[j].demon,
+                            result,
+                        }),
+                    ));
+                }
+                next_parties.extend(self.sacrificial_parties(party, i, j, &next_party, goal));
+            }
+        }
+        if party.len() < MAX_PARTY_SIZE {
+            for demon in purchases {
+                if !Self::contains(party, demon) {
+                    next_parties.push((
+                        Self::with_demon(party.clone(), self.party_demon(demon, Vec::new(), goal)),
+                        PlanAction::Purchase(demon),
+                    ));
+                }
+            }
+        }
+        next_parties
+    }
+
+    /// The parties obtained with the sacrificial fusions of the demons at the positions `i` and `j` of the party,
+    /// given the party `remaining` left by their normal fusion.
+    fn sacrificial_parties(
+        &self,
+        party: &Party<'a>,
+        i: usize,
+        j: usize,
+        remaining: &Party<'a>,
+        goal: &[&'a Skill],
+    ) -> Vec<(Party<'a>, PlanAction<'a>)> {
+        let mut next_parties = Vec::new();
+        for k in 0..remaining.len() {
+            if k > 0 && remaining[k - 1] == remaining[k] {
+                continue;
+            }
+            let Some(fusion) =
+                self.engine
+                    .sacrificial_fusion(party[i].demon, party[j].demon, remaining[k].demon)
+            else {
+                continue;
+            };
+            let result = self.fused_party_demon(
+                fusion.result,
+                &party[i],
+                &party[j],
+                Some(&remaining[k]),
+                goal,
+            );
+            let mut next_party = remaining.clone();
+            next_party.remove(k);
+            if Self::contains(&next_party, fusion.result) {
+                continue;
+            }
+            next_parties.push((
+                Self::with_demon(next_party, result),
+                PlanAction::SacrificialFusion(fusion),
+            ));
+        }
+        next_parties
+    }
+
+    /// Whether the party holds the demon, whatever the skills it carries.
+    fn contains(party: &Party<'a>, demon: &Demon) -> bool {
+        party.iter().any(|member| member.demon.name == demon.name)
+    }
+
+    /// Add a demon to a party, keeping it sorted by name then by skills.
+    fn with_demon(mut party: Party<'a>, member: PartyDemon<'a>) -> Party<'a> {
+        let position = party.partition_point(|other| other.key() < member.key());
+        party.insert(position, member);
+        party
+    }
+    /// The actions leading to the party `party` from the starting party.
+    fn actions_to(
+        previous: &HashMap<Party<'a>, (Party<'a>, PlanAction<'a>)>,
+        mut party: Party<'a>,
+    ) -> Vec<PlanAction<'a>> {
+        let mut actions = Vec::new();
+        while let Some((previous_party, action)) = previous.get(&party) {
+            actions.push(*action);
+            party = previous_party.clone();
+        }
+        actions.reverse();
+        actions
+    }
+}
+
+/// The fusions of the plan as a list of actions of the `shortest_plan/3` predicate of the planner.
+///
+/// Return an error when the plan buys a demon, since the planner cannot buy demons.
+pub fn plan_to_prolog_term(
+    plan: &Plan,
+    name_representation: NameRepresentation,
+) -> Result<PrologTerm, ErrorUnsupportedPurchase> {
+    let demon_term =
+        |demon: &Demon| demon_knowledge_base::generate_a_prolog_fact(demon, name_representation);
+    Ok(PrologTerm::List(
+        plan.actions
+            .iter()
+            .map(|action| match action {
+                PlanAction::NormalFusion(fusion) => Ok(PrologTerm::compound(
+                    "normal_fusion",
+                    vec![demon_term(fusion.demon1), demon_term(fusion.demon2)],
+                )),
+                PlanAction::SacrificialFusion(fusion) => Ok(PrologTerm::compound(
+                    "sacrificial_fusion",
+                    vec![
+                        demon_term(fusion.demon1),
+                        demon_term(fusion.demon2),
+                        demon_term(fusion.sacrifice),
+                    ],
+                )),
+                PlanAction::Purchase(demon) => Err(ErrorUnsupportedPurchase {
+                    demon: demon.name.clone(),
+                }),
+            })
+            .collect::<Result<_, _>>()?,
+    ))
+}
+
+/// The plan as text, one action per line.
+pub fn plan_to_text(plan: &Plan) -> String {
+    let lines: Vec<String> = plan
+        .actions
+        .iter()
+        .enumerate()
+        .map(|(i, action)| match action {
+            PlanAction::NormalFusion(fusion) => format!(
+                "{}. {} + {} -> {}",
+                i + 1,
+                fusion.demon1.name,
+                fusion.demon2.name,
+                fusion.result.name
+            ),
+            PlanAction::SacrificialFusion(fusion) => format!(
+                "{}. {} + {} sacrificing {} -> {}",
+                i + 1,
+                fusion.demon1.name,
+                fusion.demon2.name,
+                fusion.sacrifice.name,
+                fusion.result.name
+            ),
+            PlanAction::Purchase(demon) => format!("{}. buy {}", i + 1, demon.name),
+        })
+        .collect();
+    lines.join("\n")
+}
+
+/// The proof that no plan produces the demon `target` with the skills `skills` as text.
+pub fn unreachability_to_text(
+    target: &Demon,
+    skills: &[&Skill],
+    unreachability: &Unreachability,
+) -> String {
+    match unreachability {
+        Unreachability::UnavailableSkill(skill) => format!(
+            "no demon of the party carries '{}' and no demon learns it at its base level",
+            skill.name
+        ),
+        Unreachability::UninheritableSkill(skill) => format!(
+            "the race '{}' of '{}' cannot inherit the {} skill '{}' and '{}' does not learn it at its base level",
+            target.race, target.name, skill.category, skill.name, target.name
+        ),
+        Unreachability::UnreachableDemon => format!(
+            "no sequence of normal fusions of the party and the compendium produces '{}'",
+            target.name
+        ),
+        Unreachability::Exhausted { explored_parties } => format!(
+            "no sequence of actions produces '{}' with {} after exploring {} parties",
+            target.name,
+            skills
+                .iter()
+                .map(|skill| format!("'{}'", skill.name))
+                .collect::<Vec<String>>()
+                .join(", "),
+            explored_parties
+        ),
+    }
+}
+
+#[cfg(test)]
+mod fusion_planner_test {
+    use super::*;
+    use crate::fusion::fusion_test_model::*;
+
+    fn party<'a>(model: &'a Model, names: &[&str]) -> Vec<&'a Demon> {
+        names
+            .iter()
+            .map(|name| model.demon(name).unwrap())
+            .collect()
+    }
+
+    fn members<'a>(model: &'a Model, names: &[&str]) -> Vec<PartyDemon<'a>> {
+        names
+            .iter()
+            .map(|name| PartyDemon::new(model.demon(name).unwrap(), vec![]))
+            .collect()
+    }
+
+    /// Pixie and Kodama with their innate skills, and Ikusa carrying Agi.
+    fn party_with_agi<'a>(planner: &FusionPlanner<'a>) -> Vec<PartyDemon<'a>> {
+        let mut party = innate_members(planner, &["Pixie", "Kodama"]);
+        party.push(PartyDemon::new(
+            planner.model.demon("Ikusa").unwrap(),
+            vec![planner.model.skill("Agi").unwrap()],
+        ));
+        party
+    }
+
+    fn innate_members<'a>(planner: &FusionPlanner<'a>, names: &[&str]) -> Vec<PartyDemon<'a>> {
+        names
+            .iter()
+            .map(|name| planner.innate_party_demon(planner.model.demon(name).unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn should_return_an_empty_plan_given_the_target_in_the_party() -> Result<(), Error> {
+        let model = a_model();
+        let planner = FusionPlanner::new(&model);
+
+        let plan = planner.shortest_plan(
+            &party(&model, &["Pixie", "Kodama"]),
+            model.demon("Pixie").unwrap(),
+        )?;
+
+        assert_eq!(plan, Some(Plan { actions: vec![] }));
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_a_plan_of_one_fusion() -> Result<(), Error> {
+        let model = a_model();
+        let planner = FusionPlanner::new(&model);
+
+        let plan = planner.shortest_plan(
+            &party(&model, &["Pixie", "Kodama"]),
+            model.demon("Shikigami").unwrap(),
+        )?;
+
+        assert_eq!(
+            plan,
+            Some(Plan {
+                actions: vec![PlanAction::NormalFusion(NormalFusion {
+                    demon1: model.demon("Kodama").unwrap(),
+                    demon2: model.demon("Pixie").unwrap(),
+                    result: model.demon("Shikigami").unwrap(),
+                })],
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_the_shortest_plan_of_many_fusions() -> Result<(), Error> {
+        let model = a_model();
+        let planner = FusionPlanner::new(&model);
+
+        let plan = planner
+            .shortest_plan(
+                &party(&model, &["Pixie", "Kodama", "Hua Po"]),
+                model.demon("Uzume").unwrap(),
+            )?
+            .unwrap();
+
+        assert_eq!(plan.actions.len(), 2);
+        assert!(matches!(
+            plan.actions[1],
+            PlanAction::NormalFusion(NormalFusion { result, .. }) if result.name == "Uzume"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_no_plan_given_an_unreachable_demon() -> Result<(), Error> {
+        let model = a_model();
+        let planner = FusionPlanner::new(&model);
+
+        let plan = planner.shortest_plan(
+            &party(&model, &["Pixie", "Kodama"]),
+            model.demon("Sarasvati").unwrap(),
+        )?;
+
+        assert_eq!(plan, None);
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_an_error_given_a_party_too_large() {
+        let model = a_model();
+        let planner = FusionPlanner::new(&model);
+        let party: Vec<&Demon> = model.demons.iter().chain(model.demons.iter()).collect();
+
+        let plan = planner.shortest_plan(&party, model.demon("Pixie").unwrap());
+
+        assert!(plan.is_err());
+    }
+
+    #[test]
+    fn should_return_the_recruitable_ingredients_of_a_demon() {
+        let mut model = a_model();
+        model.recruitments = ["Pixie", "Kodama", "Oni", "Sudama"]
+            .into_iter()
+            .map(|name| Recruitment {
+                demon: name.to_string(),
+                area: "Ginza".to_string(),
+                kagutsuchi_phase: None,
+            })
+            .collect();
+        let planner = FusionPlanner::new(&model);
+
+        let ingredients: Vec<&str> = planner
+            .recruitable_ingredients("Ginza", &[], model.demon("Uzume").unwrap())
+            .iter()
+            .map(|demon| demon.name.as_str())
+            .collect();
+
+        assert_eq!(ingredients, vec!["Kodama", "Pixie", "Sudama"]);
+        assert!(planner
+            .recruitable_ingredients("Ikebukuro", &[], model.demon("Uzume").unwrap())
+            .is_empty());
+    }
+
+    #[test]
+    fn should_return_the_recruitable_ingredients_of_a_special_fusion() {
+        let mut model = a_model();
+        model.special_fusion_recipes = vec![SpecialFusionRecipe {
+            iri: "http://example.com/recipe".to_string(),
+            result: "Sarasvati".to_string(),
+            ingredients: vec!["Onkot".to_string(), "Uzume".to_string()],
+            kagutsuchi_phases: vec![KagutsuchiPhase::FULL],
+            prerequisites: vec![],
+        }];
+        model.recruitments = vec![Recruitment {
+            demon: "Onkot".to_string(),
+            area: "Ginza".to_string(),
+            kagutsuchi_phase: None,
+        }];
+        let planner = FusionPlanner::new(&model);
+
+        let ingredients = planner.recruitable_ingredients(
+            "Ginza",
+            &party(&model, &["Uzume"]),
+            model.demon("Sarasvati").unwrap(),
+        );
+
+        assert_eq!(ingredients, vec![model.demon("Onkot").unwrap()]);
+        assert!(planner
+            .recruitable_ingredients("Ginza", &[], model.demon("Sarasvati").unwrap())
+            .is_empty());
+        assert!(planner
+            .with_phase(KagutsuchiPhase::HALF)
+            .recruitable_ingredients(
+                "Ginza",
+                &party(&model, &["Uzume"]),
+                model.demon("Sarasvati").unwrap()
+            )
+            .is_empty());
+    }
+
+    #[test]
+    fn should_return_the_ingredients_recruitable_during_the_phase() {
+        let mut model = a_model();
+        model.recruitments = vec![
+            Recruitment {
+                demon: "Pixie".to_string(),
+                area: "Ginza".to_string(),
+                kagutsuchi_phase: Some(KagutsuchiPhase::FULL),
+            },
+            Recruitment {
+                demon: "Kodama".to_string(),
+                area: "Ginza".to_string(),
+                kagutsuchi_phase: None,
+            },
+        ];
+        let shikigami = model.demon("Shikigami").unwrap();
+
+        let planner = FusionPlanner::new(&model).with_phase(KagutsuchiPhase::FULL);
+        assert_eq!(
+            planner.recruitable_ingredients("Ginza", &[], shikigami),
+            party(&model, &["Kodama", "Pixie"])
+        );
+
+        let planner = FusionPlanner::new(&model).with_phase(KagutsuchiPhase::NEW);
+        assert!(planner
+            .recruitable_ingredients("Ginza", &[], shikigami)
+            .is_empty());
+        assert_eq!(
+            planner.recruitable_ingredients("Ginza", &party(&model, &["Pixie"]), shikigami),
+            party(&model, &["Kodama"])
+        );
+    }
+
+    #[test]
+    fn should_buy_an_ingredient() -> Result<(), Error> {
+        let model = a_model();
+        let planner = FusionPlanner::new(&model)
+            .with_compendium(&party(&model, &["Pixie", "Kodama"]))
+            .with_max_steps(2);
+
+        let plan = planner
+            .shortest_plan(
+                &party(&model, &["Kodama"]),
+                model.demon("Shikigami").unwrap(),
+            )?
+            .unwrap();
+
+        assert_eq!(
+            plan.actions,
+            vec![
+                PlanAction::Purchase(model.demon("Pixie").unwrap()),
+                PlanAction::NormalFusion(NormalFusion {
+                    demon1: model.demon("Kodama").unwrap(),
+                    demon2: model.demon("Pixie").unwrap(),
+                    result: model.demon("Shikigami").unwrap(),
+                }),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_buy_the_target_when_it_is_in_the_compendium() -> Result<(), Error> {
+        let model = a_model();
+        let planner = FusionPlanner::new(&model)
+            .with_compendium(&party(&model, &["High Pixie", "Shikigami"]))
+            .with_max_steps(2);
+
+        let plan = planner
+            .shortest_plan(
+                &party(&model, &["Kodama"]),
+                model.demon("Shikigami").unwrap(),
+            )?
+            .unwrap();
+
+        assert_eq!(
+            plan.actions,
+            vec![PlanAction::Purchase(model.demon("Shikigami").unwrap())]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_not_fuse_a_demon_already_in_the_party() {
+        let model = a_model();
+        let planner = FusionPlanner::new(&model);
+        let party = innate_members(&planner, &["Kodama", "Pixie", "Shikigami"]);
+
+        let next_parties = planner.next_parties(&party, &[], &[]);
+
+        for (next_party, _) in &next_parties {
+            let mut names: Vec<&str> = next_party
+                .iter()
+                .map(|member| member.demon.name.as_str())
+                .collect();
+            names.dedup();
+            assert_eq!(names.len(), next_party.len());
+        }
+        assert!(next_parties.iter().any(|(_, action)| *action
+            == PlanAction::SacrificialFusion(SacrificialFusion {
+                demon1: model.demon("Kodama").unwrap(),
+                demon2: model.demon("Pixie").unwrap(),
+                sacrifice: model.demon("Shikigami").unwrap(),
+                result: model.demon("Shikigami").unwrap(),
+            })));
+        assert!(!next_parties.iter().any(|(_, action)| *action
+            == PlanAction::NormalFusion(NormalFusion {
+                demon1: model.demon("Kodama").unwrap(),
+                demon2: model.demon("Pixie").unwrap(),
+                result: model.demon("Shikigami").unwrap(),
+            })));
+    }
+
+    #[test]
+    fn should_not_buy_a_demon_given_a_full_party() -> Result<(), Error> {
+        let model = a_model();
+        let full_party: Vec<&Demon> =
+            std::iter::repeat_n(model.demon("Oni").unwrap(), MAX_PARTY_SIZE).collect();
+        let planner = FusionPlanner::new(&model)
+            .with_compendium(&party(&model, &["Pixie"]))
+            .with_max_steps(2);
+
+        let plan = planner.shortest_plan(&full_party, model.demon("Pixie").unwrap())?;
+
+        assert_eq!(plan, None);
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_no_plan_given_a_demon_unreachable_with_the_compendium() -> Result<(), Error> {
+        let model = a_model();
+        let planner =
+            FusionPlanner::new(&model).with_compendium(&party(&model, &["Pixie", "Shikigami"]));
+
+        let plan = planner.shortest_plan(
+            &party(&model, &["Kodama"]),
+            model.demon("Sarasvati").unwrap(),
+        )?;
+
+        assert_eq!(plan, None);
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_an_error_given_a_compendium_without_a_bound() {
+        let model = a_model();
+        let planner = FusionPlanner::new(&model).with_compendium(&party(&model, &["Pixie"]));
+
+        let plan = planner.shortest_plan(
+            &party(&model, &["Kodama"]),
+            model.demon("Shikigami").unwrap(),
+        );
+
+        assert!(plan.is_err());
+    }
+
+    #[test]
+    fn should_not_buy_a_demon_that_cannot_feed_the_target() -> Result<(), Error> {
+        let model = a_model();
+        let planner = FusionPlanner::new(&model).with_compendium(&party(&model, &["Oni"]));
+
+        let plan = planner.shortest_plan(
+            &party(&model, &["Kodama", "Pixie"]),
+            model.demon("Shikigami").unwrap(),
+        )?;
+
+        assert_eq!(plan.map(|plan| plan.actions.len()), Some(1));
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_no_plan_given_too_few_steps() -> Result<(), Error> {
+        let model = a_model();
+        let planner = FusionPlanner::new(&model)
+            .with_compendium(&party(&model, &["Pixie"]))
+            .with_max_steps(1);
+
+        let plan = planner.shortest_plan(
+            &party(&model, &["Kodama"]),
+            model.demon("Shikigami").unwrap(),
+        )?;
+
+        assert_eq!(plan, None);
+        Ok(())
+    }
+
+    #[test]
+    fn should_prove_unreachable_a_demon_no_fusion_produces() -> Result<(), Error> {
+        let model = a_model_with_skills();
+        let planner =
+            FusionPlanner::new(&model).with_compendium(&party(&model, &["Pixie", "Shikigami"]));
+
+        let plan = planner.shortest_plan_with_skills(
+            &members(&model, &["Kodama"]),
+            model.demon("Sarasvati").unwrap(),
+            &[model.skill("Agi").unwrap()],
+        )?;
+
+        assert_eq!(
+            plan,
+            SkillPlan::Unreachable(Unreachability::UnreachableDemon)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_sacrifice_a_demon_during_the_full_phase() {
+        let model = a_model();
+        let start = members(&model, &["Kodama", "Oni", "Pixie"]);
+        let sacrificial_fusion = (
+            members(&model, &["Shikigami"]),
+            PlanAction::SacrificialFusion(SacrificialFusion {
+                demon1: model.demon("Kodama").unwrap(),
+                demon2: model.demon("Pixie").unwrap(),
+                sacrifice: model.demon("Oni").unwrap(),
+                result: model.demon("Shikigami").unwrap(),
+            }),
+        );
+
+        let planner = FusionPlanner::new(&model).with_phase(KagutsuchiPhase::FULL);
+        assert!(planner
+            .next_parties(&start, &[], &[])
+            .contains(&sacrificial_fusion));
+
+        let planner = FusionPlanner::new(&model).with_phase(KagutsuchiPhase::HALF);
+        assert!(!planner
+            .next_parties(&start, &[], &[])
+            .contains(&sacrificial_fusion));
+    }
+
+    #[test]
+    fn should_return_a_plan_given_a_skill_learned_by_the_target() -> Result<(), Error> {
+        let model = a_model_with_skills();
+        let planner = FusionPlanner::new(&model);
+
+        let plan = planner.shortest_plan_with_skills(
+            &innate_members(&planner, &["Pixie", "Kodama"]),
+            model.demon("Shikigami").unwrap(),
+            &[model.skill("Lunge").unwrap()],
+        )?;
+
+        assert_eq!(
+            plan,
+            SkillPlan::Reachable(Plan {
+                actions: vec![PlanAction::NormalFusion(NormalFusion {
+                    demon1: model.demon("Kodama").unwrap(),
+                    demon2: model.demon("Pixie").unwrap(),
+                    result: model.demon("Shikigami").unwrap(),
+                })],
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_inherit_a_skill_from_a_sacrificed_demon() -> Result<(), Error> {
+        let model = a_model_with_skills();
+        let planner = FusionPlanner::new(&model).with_phase(KagutsuchiPhase::FULL);
+
+        let plan = planner.shortest_plan_with_skills(
+            &party_with_agi(&planner),
+            model.demon("Shikigami").unwrap(),
+            &[model.skill("Agi").unwrap(), model.skill("Lunge").unwrap()],
+        )?;
+
+        assert_eq!(
+            plan,
+            SkillPlan::Reachable(Plan {
+                actions: vec![PlanAction::SacrificialFusion(SacrificialFusion {
+                    demon1: model.demon("Kodama").unwrap(),
+                    demon2: model.demon("Pixie").unwrap(),
+                    sacrifice: model.demon("Ikusa").unwrap(),
+                    result: model.demon("Shikigami").unwrap(),
+                })],
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_inherit_a_skill_carried_by_an_ingredient() -> Result<(), Error> {
+        let model = a_model_with_skills();
+        let planner = FusionPlanner::new(&model);
+        let party = vec![
+            PartyDemon::new(
+                model.demon("Pixie").unwrap(),
+                vec![model.skill("Agi").unwrap()],
+            ),
+            planner.innate_party_demon(model.demon("Kodama").unwrap()),
+        ];
+
+        let plan = planner.shortest_plan_with_skills(
+            &party,
+            model.demon("Shikigami").unwrap(),
+            &[model.skill("Agi").unwrap()],
+        )?;
+
+        assert!(
+            matches!(plan, SkillPlan::Reachable(Plan { ref actions, .. }) if actions.len() == 1)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_prove_unreachable_a_skill_that_no_demon_carries() -> Result<(), Error> {
+        let mut model = a_model_with_skills();
+        model.skills.push(skill("Hama", "Fire"));
+        let planner = FusionPlanner::new(&model);
+
+        let plan = planner.shortest_plan_with_skills(
+            &innate_members(&planner, &["Pixie", "Kodama"]),
+            model.demon("Shikigami").unwrap(),
+            &[model.skill("Hama").unwrap()],
+        )?;
+
+        assert_eq!(
+            plan,
+            SkillPlan::Unreachable(Unreachability::UnavailableSkill(
+                model.skill("Hama").unwrap()
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_prove_unreachable_a_skill_the_race_cannot_inherit() -> Result<(), Error> {
+        let model = a_model_with_skills();
+        let planner = FusionPlanner::new(&model);
+
+        let plan = planner.shortest_plan_with_skills(
+            &innate_members(&planner, &["Pixie", "Kodama"]),
+            model.demon("Shikigami").unwrap(),
+            &[model.skill("Zan").unwrap()],
+        )?;
+
+        assert_eq!(
+            plan,
+            SkillPlan::Unreachable(Unreachability::UninheritableSkill(
+                model.skill("Zan").unwrap()
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_prove_unreachable_a_skill_after_exhausting_the_parties() -> Result<(), Error> {
+        let model = a_model_with_skills();
+        let planner = FusionPlanner::new(&model).with_phase(KagutsuchiPhase::HALF);
+
+        let plan = planner.shortest_plan_with_skills(
+            &party_with_agi(&planner),
+            model.demon("Shikigami").unwrap(),
+            &[model.skill("Agi").unwrap()],
+        )?;
+
+        assert!(matches!(
+            plan,
+            SkillPlan::Unreachable(Unreachability::Exhausted { explored_parties }) if explored_parties > 1
+        ));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod plan_to_prolog_term_test {
+    use super::*;
+    use crate::fusion::fusion_test_model::*;
+
+    #[test]
+    fn should_return_an_error_given_a_plan_buying_a_demon() {
+        let model = a_model();
+        let plan = Plan {
+            actions: vec![
+                PlanAction::Purchase(model.demon("Pixie").unwrap()),
+                PlanAction::NormalFusion(NormalFusion {
+                    demon1: model.demon("Kodama").unwrap(),
+                    demon2: model.demon("Pixie").unwrap(),
+                    result: model.demon("Shikigami").unwrap(),
+                }),
+            ],
+        };
+
+        let resp = plan_to_prolog_term(&plan, NameRepresentation::Atom);
+
+        assert_eq!(
+            resp.err().map(|error| error.to_string()),
+            Some("the plan buys the demon 'Pixie', but the actions of the Prolog planner cannot describe a purchase".to_string())
+        );
+    }
+
+    #[test]
+    fn should_return_the_fusions_of_the_plan() -> Result<(), ErrorUnsupportedPurchase> {
+        let model = a_model();
+        let plan = Plan {
+            actions: vec![PlanAction::NormalFusion(NormalFusion {
+                demon1: model.demon("Kodama").unwrap(),
+                demon2: model.demon("Pixie").unwrap(),
+                result: model.demon("Shikigami").unwrap(),
+            })],
+        };
+
+        let resp = plan_to_prolog_term(&plan, NameRepresentation::Atom)?;
+
+        assert_eq!(
+            resp.to_string(),
+            "[normal_fusion(demon('Kodama', 'Jirae', 5, false), demon('Pixie', 'Fairy', 2, false))]"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_a_sacrificial_fusion() -> Result<(), ErrorUnsupportedPurchase> {
+        let model = a_model();
+        let plan = Plan {
+            actions: vec![PlanAction::SacrificialFusion(SacrificialFusion {
+                demon1: model.demon("Kodama").unwrap(),
+                demon2: model.demon("Pixie").unwrap(),
+                sacrifice: model.demon("Oni").unwrap(),
+                result: model.demon("Shikigami").unwrap(),
+            })],
+        };
+
+        let resp = plan_to_prolog_term(&plan, NameRepresentation::Atom)?;
+
+        assert_eq!(
+            resp.to_string(),
+            "[sacrificial_fusion(demon('Kodama', 'Jirae', 5, false), demon('Pixie', 'Fairy', 2, false), demon('Oni', 'Brute', 30, false))]"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_an_empty_list_given_an_empty_plan() -> Result<(), ErrorUnsupportedPurchase> {
+        let plan = Plan { actions: vec![] };
+
+        let resp = plan_to_prolog_term(&plan, NameRepresentation::Atom)?;
+
+        assert_eq!(resp.to_string(), "[]");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod plan_to_text_test {
+    use super::*;
+    use crate::fusion::fusion_test_model::*;
+
+    #[test]
+    fn should_return_the_purchases_and_the_fusions() {
+        let model = a_model();
+        let plan = Plan {
+            actions: vec![
+                PlanAction::Purchase(model.demon("Pixie").unwrap()),
+                PlanAction::NormalFusion(NormalFusion {
+                    demon1: model.demon("Kodama").unwrap(),
+                    demon2: model.demon("Pixie").unwrap(),
+                    result: model.demon("Shikigami").unwrap(),
+                }),
+            ],
+        };
+
+        let resp = plan_to_text(&plan);
+
+        assert_eq!(resp, "1. buy Pixie\n2. Kodama + Pixie -> Shikigami");
+    }
+
+    #[test]
+    fn should_return_a_sacrificial_fusion() {
+        let model = a_model();
+        let plan = Plan {
+            actions: vec![PlanAction::SacrificialFusion(SacrificialFusion {
+                demon1: model.demon("Kodama").unwrap(),
+                demon2: model.demon("Pixie").unwrap(),
+                sacrifice: model.demon("Oni").unwrap(),
+                result: model.demon("Shikigami").unwrap(),
+            })],
+        };
+
+        let resp = plan_to_text(&plan);
+
+        assert_eq!(resp, "1. Kodama + Pixie sacrificing Oni -> Shikigami");
+    }
+}
+
+#[cfg(test)]
+mod unreachability_to_text_test {
+    use super::*;
+    use crate::fusion::fusion_test_model::*;
+
+    #[test]
+    fn should_return_the_skill_the_race_cannot_inherit() {
+        let model = a_model_with_skills();
+        let zan = model.skill("Zan").unwrap();
+
+        let resp = unreachability_to_text(
+            model.demon("Shikigami").unwrap(),
+            &[zan],
+            &Unreachability::UninheritableSkill(zan),
+        );
+
+        assert_eq!(
+            resp,
+            "the race 'Brute' of 'Shikigami' cannot inherit the Force skill 'Zan' and 'Shikigami' does not learn it at its base level"
+        );
+    }
+
+    #[test]
+    fn should_return_the_unreachable_demon() {
+        let model = a_model_with_skills();
+
+        let resp = unreachability_to_text(
+            model.demon("Sarasvati").unwrap(),
+            &[model.skill("Agi").unwrap()],
+            &Unreachability::UnreachableDemon,
+        );
+
+        assert_eq!(
+            resp,
+            "no sequence of normal fusions of the party and the compendium produces 'Sarasvati'"
+        );
+    }
+
+    #[test]
+    fn should_return_the_explored_parties() {
+        let model = a_model_with_skills();
+
+        let resp = unreachability_to_text(
+            model.demon("Shikigami").unwrap(),
+            &[model.skill("Agi").unwrap(), model.skill("Lunge").unwrap()],
+            &Unreachability::Exhausted {
+                explored_parties: 7,
+            },
+        );
+
+        assert_eq!(
+            resp,
+            "no sequence of actions produces 'Shikigami' with 'Agi', 'Lunge' after exploring 7 parties"
+        );
+    }
+}
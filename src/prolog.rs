@@ -0,0 +1,431 @@
+// smt-nocturne-prolog-kb-generator: A generates of a Prolog knowledge base
+// to describe demons, their fusion, and fusion mechanics for the game 
+// Shin Megami Tensei III: Nocturne.
+// Copyright (C) 2025  Bryan-Elliott Tam
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::error::*;
+use std::fmt;
+use std::str::FromStr;
+
+/// A Prolog term that can be written in the ISO syntax.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PrologTerm {
+    /// An atom, quoted only when it cannot be written unquoted.
+    Atom(String),
+    /// An atom always written between single quotes.
+    QuotedAtom(String),
+    /// A string written between double quotes.
+    String(String),
+    /// An integer.
+    Integer(i64),
+    /// A boolean written as the atom `true` or `false`.
+    Boolean(bool),
+    /// A list of terms.
+    List(Vec<PrologTerm>),
+    /// A compound term made of a functor and its arguments.
+    Compound(String, Vec<PrologTerm>),
+}
+
+impl PrologTerm {
+    /// Create a compound term.
+    pub fn compound(functor: &str, arguments: Vec<PrologTerm>) -> Self {
+        PrologTerm::Compound(functor.to_string(), arguments)
+    }
+
+    /// Write the term as a Prolog fact.
+    pub fn to_fact(&self) -> String {
+        format!("{self}.")
+    }
+
+    /// The predicate indicator of the term if it is callable.
+    pub fn predicate_indicator(&self) -> Option<PredicateIndicator> {
+        match self {
+            PrologTerm::Atom(atom) | PrologTerm::QuotedAtom(atom) => {
+                Some(PredicateIndicator::new(atom, 0))
+            }
+            PrologTerm::Compound(functor, arguments) => {
+                Some(PredicateIndicator::new(functor, arguments.len()))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The indicator `Name/Arity` of a predicate.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PredicateIndicator {
+    pub name: String,
+    pub arity: usize,
+}
+
+impl PredicateIndicator {
+    pub fn new(name: &str, arity: usize) -> Self {
+        Self {
+            name: name.to_string(),
+            arity,
+        }
+    }
+}
+
+impl fmt::Display for PredicateIndicator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_atom(f, &self.name)?;
+        write!(f, "/{}", self.arity)
+    }
+}
+
+/// Create the directives declaring a Prolog module.
+///
+/// The export list contains the `declared_predicates` followed by the predicates of the `facts`.
+/// Declared predicates without facts are declared dynamic so that calling them fails instead of raising an existence error,
+/// and predicates whose facts are not contiguous are declared discontiguous.
+pub fn module_directives(
+    module_name: &str,
+    declared_predicates: &[PredicateIndicator],
+    facts: &[PrologTerm],
+) -> Vec<String> {
+    let mut exported_predicates: Vec<PredicateIndicator> = declared_predicates.to_vec();
+    let mut discontiguous_predicates: Vec<PredicateIndicator> = Vec::new();
+    let mut previous_predicate: Option<PredicateIndicator> = None;
+    let mut predicates_with_facts: Vec<PredicateIndicator> = Vec::new();
+
+    for predicate in facts.iter().filter_map(PrologTerm::predicate_indicator) {
+        if previous_predicate.as_ref() == Some(&predicate) {
+            continue;
+        }
+        if predicates_with_facts.contains(&predicate) {
+            if !discontiguous_predicates.contains(&predicate) {
+                discontiguous_predicates.push(predicate.clone());
+            }
+        } else {
+            predicates_with_facts.push(predicate.clone());
+        }
+        if !exported_predicates.contains(&predicate) {
+            exported_predicates.push(predicate.clone());
+        }
+        previous_predicate = Some(predicate);
+    }
+
+    let export_list: Vec<String> = exported_predicates
+        .iter()
+        .map(PredicateIndicator::to_string)
+        .collect();
+    let mut directives = vec![format!(
+        ":- module({}, [{}]).",
+        PrologTerm::Atom(module_name.to_string()),
+        export_list.join(", ")
+    )];
+
+    for predicate in exported_predicates
+        .iter()
+        .filter(|predicate| !predicates_with_facts.contains(predicate))
+    {
+        directives.push(format!(":- dynamic({predicate})."));
+    }
+    for predicate in discontiguous_predicates {
+        directives.push(format!(":- discontiguous({predicate})."));
+    }
+
+    directives
+}
+
+impl fmt::Display for PrologTerm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PrologTerm::Atom(atom) => write_atom(f, atom),
+            PrologTerm::QuotedAtom(atom) => write_quoted(f, atom, '\''),
+            PrologTerm::String(string) => write_quoted(f, string, '"'),
+            PrologTerm::Integer(integer) => write!(f, "{integer}"),
+            PrologTerm::Boolean(boolean) => write!(f, "{boolean}"),
+            PrologTerm::List(elements) => {
+                write!(f, "[")?;
+                write_arguments(f, elements)?;
+                write!(f, "]")
+            }
+            PrologTerm::Compound(functor, arguments) => {
+                write_atom(f, functor)?;
+                if arguments.is_empty() {
+                    return Ok(());
+                }
+                write!(f, "(")?;
+                write_arguments(f, arguments)?;
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+fn write_arguments(f: &mut fmt::Formatter<'_>, arguments: &[PrologTerm]) -> fmt::Result {
+    for (i, argument) in arguments.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{argument}")?;
+    }
+    Ok(())
+}
+
+fn write_atom(f: &mut fmt::Formatter<'_>, atom: &str) -> fmt::Result {
+    if is_unquoted_atom(atom) {
+        write!(f, "{atom}")
+    } else {
+        write_quoted(f, atom, '\'')
+    }
+}
+
+/// An atom can be written without quotes if it starts with a lowercase letter
+/// and is only made of alphanumeric characters and underscores.
+fn is_unquoted_atom(atom: &str) -> bool {
+    let mut chars = atom.chars();
+    match chars.next() {
+        Some(first) if first.is_ascii_lowercase() => {
+            chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+        _ => false,
+    }
+}
+
+/// Write a quoted atom or a string using the ISO escape sequences.
+/// Non ASCII characters are written as hexadecimal escape sequences.
+fn write_quoted(f: &mut fmt::Formatter<'_>, value: &str, quote: char) -> fmt::Result {
+    write!(f, "{quote}")?;
+    for c in value.chars() {
+        match c {
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\t' => write!(f, "\\t")?,
+            '\r' => write!(f, "\\r")?,
+            c if c == quote => write!(f, "\\{quote}")?,
+            c if c.is_ascii() && !c.is_ascii_control() => write!(f, "{c}")?,
+            c => write!(f, "\\x{:x}\\", c as u32)?,
+        }
+    }
+    write!(f, "{quote}")
+}
+
+/// The representation of the names of the entities in the knowledge base.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NameRepresentation {
+    /// Names are written as quoted atoms.
+    #[default]
+    Atom,
+    /// Names are written as double-quoted strings.
+    String,
+}
+
+impl NameRepresentation {
+    /// Create the term of a name.
+    pub fn term(&self, name: &str) -> PrologTerm {
+        match self {
+            NameRepresentation::Atom => PrologTerm::QuotedAtom(name.to_string()),
+            NameRepresentation::String => PrologTerm::String(name.to_string()),
+        }
+    }
+}
+
+impl FromStr for NameRepresentation {
+    type Err = ErrorUnknownNameRepresentation;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "atom" => Ok(NameRepresentation::Atom),
+            "string" => Ok(NameRepresentation::String),
+            _ => Err(ErrorUnknownNameRepresentation {
+                representation: s.to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod prolog_term_test {
+    use super::*;
+
+    #[test]
+    fn should_write_an_unquoted_atom() {
+        assert_eq!(PrologTerm::Atom("foo_Bar1".to_string()).to_string(), "foo_Bar1");
+    }
+
+    #[test]
+    fn should_quote_an_atom_that_cannot_be_unquoted() {
+        assert_eq!(PrologTerm::Atom("Foo".to_string()).to_string(), "'Foo'");
+        assert_eq!(PrologTerm::Atom("foo bar".to_string()).to_string(), "'foo bar'");
+        assert_eq!(PrologTerm::Atom("".to_string()).to_string(), "''");
+    }
+
+    #[test]
+    fn should_write_a_quoted_atom() {
+        assert_eq!(PrologTerm::QuotedAtom("foo".to_string()).to_string(), "'foo'");
+    }
+
+    #[test]
+    fn should_escape_a_quoted_atom() {
+        assert_eq!(
+            PrologTerm::QuotedAtom("Jack's \\ \"Lantern\"\n".to_string()).to_string(),
+            "'Jack\\'s \\\\ \"Lantern\"\\n'"
+        );
+    }
+
+    #[test]
+    fn should_escape_a_string() {
+        assert_eq!(
+            PrologTerm::String("Jack's \"Lantern\"\t".to_string()).to_string(),
+            "\"Jack's \\\"Lantern\\\"\\t\""
+        );
+    }
+
+    #[test]
+    fn should_escape_non_ascii_characters() {
+        assert_eq!(PrologTerm::QuotedAtom("Kodama é".to_string()).to_string(), "'Kodama \\xe9\\'");
+        assert_eq!(PrologTerm::String("魔".to_string()).to_string(), "\"\\x9b54\\\"");
+    }
+
+    #[test]
+    fn should_write_integers_and_booleans() {
+        assert_eq!(PrologTerm::Integer(-3).to_string(), "-3");
+        assert_eq!(PrologTerm::Boolean(true).to_string(), "true");
+        assert_eq!(PrologTerm::Boolean(false).to_string(), "false");
+    }
+
+    #[test]
+    fn should_write_a_list() {
+        let list = PrologTerm::List(vec![PrologTerm::Integer(1), PrologTerm::Atom("a".to_string())]);
+        assert_eq!(list.to_string(), "[1, a]");
+        assert_eq!(PrologTerm::List(vec![]).to_string(), "[]");
+    }
+
+    #[test]
+    fn should_write_a_compound_term_as_a_fact() {
+        let term = PrologTerm::compound(
+            "demon",
+            vec![
+                PrologTerm::QuotedAtom("Abaddon".to_string()),
+                PrologTerm::QuotedAtom("Tyrant".to_string()),
+                PrologTerm::Integer(69),
+                PrologTerm::Boolean(false),
+            ],
+        );
+        assert_eq!(term.to_fact(), "demon('Abaddon', 'Tyrant', 69, false).");
+    }
+
+    #[test]
+    fn should_quote_a_functor_that_cannot_be_unquoted() {
+        let term = PrologTerm::compound("Foo", vec![PrologTerm::Integer(1)]);
+        assert_eq!(term.to_string(), "'Foo'(1)");
+    }
+}
+
+#[cfg(test)]
+mod module_directives_test {
+    use super::*;
+
+    fn fact(functor: &str, arity: usize) -> PrologTerm {
+        PrologTerm::compound(functor, (0..arity as i64).map(PrologTerm::Integer).collect())
+    }
+
+    #[test]
+    fn should_export_the_predicates_of_the_facts() {
+        let facts = vec![fact("demon", 4), fact("demon", 4), fact("fuse_race", 3)];
+
+        let directives = module_directives("demon", &[], &facts);
+
+        assert_eq!(
+            directives,
+            vec![":- module(demon, [demon/4, fuse_race/3]).".to_string()]
+        );
+    }
+
+    #[test]
+    fn should_declare_dynamic_the_declared_predicates_without_facts() {
+        let facts = vec![fact("demon", 4)];
+        let declared = vec![
+            PredicateIndicator::new("demon", 4),
+            PredicateIndicator::new("fuse_race", 3),
+        ];
+
+        let directives = module_directives("demon", &declared, &facts);
+
+        assert_eq!(
+            directives,
+            vec![
+                ":- module(demon, [demon/4, fuse_race/3]).".to_string(),
+                ":- dynamic(fuse_race/3).".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_declare_discontiguous_the_interleaved_predicates() {
+        let facts = vec![
+            fact("demon", 4),
+            fact("fuse_race", 3),
+            fact("demon", 4),
+            fact("fuse_race", 3),
+            fact("fuse_race", 3),
+        ];
+
+        let directives = module_directives("Demon KB", &[], &facts);
+
+        assert_eq!(
+            directives,
+            vec![
+                ":- module('Demon KB', [demon/4, fuse_race/3]).".to_string(),
+                ":- discontiguous(demon/4).".to_string(),
+                ":- discontiguous(fuse_race/3).".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_distinguish_predicates_by_arity() {
+        let facts = vec![fact("demon", 4), fact("demon", 3)];
+
+        let directives = module_directives("demon", &[], &facts);
+
+        assert_eq!(
+            directives,
+            vec![":- module(demon, [demon/4, demon/3]).".to_string()]
+        );
+    }
+}
+
+#[cfg(test)]
+mod name_representation_test {
+    use super::*;
+
+    #[test]
+    fn should_parse_a_name_representation() -> Result<(), ErrorUnknownNameRepresentation> {
+        assert_eq!(NameRepresentation::from_str("atom")?, NameRepresentation::Atom);
+        assert_eq!(NameRepresentation::from_str("string")?, NameRepresentation::String);
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_an_error_given_an_unknown_name_representation() {
+        let res = NameRepresentation::from_str("list");
+        assert!(res.is_err());
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "unknown name representation 'list', expected 'atom' or 'string'"
+        );
+    }
+
+    #[test]
+    fn should_create_the_term_of_a_name() {
+        assert_eq!(NameRepresentation::Atom.term("Pixie").to_string(), "'Pixie'");
+        assert_eq!(NameRepresentation::String.term("Pixie").to_string(), "\"Pixie\"");
+    }
+}
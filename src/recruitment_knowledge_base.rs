@@ -0,0 +1,227 @@
+// smt-nocturne-prolog-kb-generator: A generates of a Prolog knowledge base
+// to describe demons, their fusion, and fusion mechanics for the game 
+// Shin Megami Tensei III: Nocturne.
+// Copyright (C) 2025  Bryan-Elliott Tam
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::dataset::Dataset;
+use crate::model::*;
+use crate::prolog::*;
+use crate::util::*;
+use anyhow::Error;
+use oxigraph::sparql::QuerySolution;
+use std::collections::HashSet;
+
+/// Query the areas where the Shin Megami Tensei Nocture demons can be recruited from a dataset.
+pub fn query_recruitments(dataset: &Dataset) -> Result<Vec<Recruitment>, Error> {
+    query_model(dataset, GET_RECRUITMENT_QUERY, recruitment_from_solution)
+}
+
+/// create a prolog knowledge based containing the areas where Shin Megami Tensei Nocture demons can be recruited.
+///
+/// A demon recruitable in an area during many phases of Kagutsuchi is described once.
+pub fn create_prolog_recruitment_knowledge_base(
+    recruitments: &[Recruitment],
+    name_representation: NameRepresentation,
+) -> Vec<PrologTerm> {
+    let mut facts = create_prolog_knowledge_base(recruitments, |recruitment| {
+        PrologTerm::compound(
+            "recruitable",
+            vec![
+                name_representation.term(&recruitment.demon),
+                name_representation.term(&recruitment.area),
+            ],
+        )
+    });
+    let mut seen = HashSet::new();
+    facts.retain(|fact| seen.insert(fact.clone()));
+    facts
+}
+
+/// create a prolog knowledge based containing the phases of Kagutsuchi during which Shin Megami Tensei Nocture demons
+/// can be recruited in an area, for the recruitments restricted to a phase.
+pub fn create_prolog_recruitment_phase_knowledge_base(
+    recruitments: &[Recruitment],
+    name_representation: NameRepresentation,
+) -> Vec<PrologTerm> {
+    recruitments
+        .iter()
+        .filter_map(|recruitment| {
+            let phase = recruitment.kagutsuchi_phase?;
+            Some(PrologTerm::compound(
+                "recruitable",
+                vec![
+                    name_representation.term(&recruitment.demon),
+                    name_representation.term(&recruitment.area),
+                    PrologTerm::Integer(phase.value().into()),
+                ],
+            ))
+        })
+        .collect()
+}
+
+fn recruitment_from_solution(solution_map: QuerySolution) -> Result<Recruitment, Error> {
+    let demon = solution_variable(&solution_map, "demon")?;
+    let area = solution_variable(&solution_map, "area")?;
+
+    Ok(Recruitment {
+        demon: literal_string_to_string(demon, "demon")?,
+        area: literal_string_to_string(area, "area")?,
+        kagutsuchi_phase: solution_map
+            .get("phase")
+            .map(|phase| literal_kagutsuchi_phase(phase, "phase"))
+            .transpose()?,
+    })
+}
+
+const GET_RECRUITMENT_QUERY: &str = "
+PREFIX vocab: <https://constraintautomaton.github.io/smt-nocture-db-to-rdf/vocabulary.ttl#>
+PREFIX schema: <https://schema.org/>
+
+SELECT ?demon ?area ?phase WHERE {
+    ?recruitment vocab:recruitableDemon ?demonIri ;
+        vocab:inArea ?areaIri .
+
+    OPTIONAL { ?recruitment vocab:kagutsuchiPhase ?phase . }
+
+    ?demonIri schema:name ?demon .
+    ?areaIri schema:name ?area .
+}";
+
+#[cfg(test)]
+mod recruitment_from_solution_test {
+    use super::*;
+    use oxigraph::model::*;
+
+    #[test]
+    fn should_return_a_recruitment_without_phase() -> Result<(), Error> {
+        let solution_map: QuerySolution = QuerySolution::from((
+            vec![Variable::new("demon")?, Variable::new("area")?],
+            vec![
+                Term::Literal(Literal::from("a")).into(),
+                Term::Literal(Literal::from("b")).into(),
+            ],
+        ));
+
+        let res = recruitment_from_solution(solution_map)?;
+
+        assert_eq!(
+            res,
+            Recruitment {
+                demon: "a".to_string(),
+                area: "b".to_string(),
+                kagutsuchi_phase: None,
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_an_error_given_the_area_is_not_a_literal() -> Result<(), Error> {
+        let solution_map: QuerySolution = QuerySolution::from((
+            vec![Variable::new("demon")?, Variable::new("area")?],
+            vec![
+                Term::Literal(Literal::from("a")).into(),
+                Term::NamedNode(NamedNode::new("http://example.com/b")?).into(),
+            ],
+        ));
+
+        let res = recruitment_from_solution(solution_map);
+
+        assert_eq!(
+            res.err().map(|error| error.to_string()),
+            Some("the value of the variable 'area' is not a literal".to_string())
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod query_recruitments_test {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn recruitments() -> Result<Vec<Recruitment>, Error> {
+        let demon_file_path = PathBuf::from("./test_files/test_valid_demon.ttl");
+        let recruitment_file_path = PathBuf::from("./test_files/test_valid_recruitment.ttl");
+        Dataset::load(&[&demon_file_path, &recruitment_file_path])
+            .and_then(|dataset| query_recruitments(&dataset))
+    }
+
+    #[test]
+    fn should_return_a_prolog_knowledge_base() -> Result<(), Error> {
+        let expected_knowledge_base: HashSet<String> = vec![
+            "recruitable('Aeros', 'Shinjuku Medical Center').".to_string(),
+            "recruitable('Aeros', 'Ginza').".to_string(),
+            "recruitable('Abaddon', 'Ginza').".to_string(),
+        ]
+        .into_iter()
+        .collect();
+
+        let resp =
+            create_prolog_recruitment_knowledge_base(&recruitments()?, NameRepresentation::Atom);
+
+        assert_eq!(resp.len(), 3);
+        assert_eq!(
+            resp.iter()
+                .map(PrologTerm::to_fact)
+                .collect::<HashSet<String>>(),
+            expected_knowledge_base
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_a_prolog_knowledge_base_of_the_phases() -> Result<(), Error> {
+        let resp = create_prolog_recruitment_phase_knowledge_base(
+            &recruitments()?,
+            NameRepresentation::Atom,
+        );
+
+        assert_eq!(
+            resp.iter()
+                .map(PrologTerm::to_fact)
+                .collect::<Vec<String>>(),
+            vec!["recruitable('Aeros', 'Ginza', 0).".to_string()]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_describe_a_demon_once_per_area() {
+        let recruitment = Recruitment {
+            demon: "a".to_string(),
+            area: "b".to_string(),
+            kagutsuchi_phase: Some(KagutsuchiPhase::NEW),
+        };
+        let recruitments = vec![
+            recruitment.clone(),
+            Recruitment {
+                kagutsuchi_phase: Some(KagutsuchiPhase::FULL),
+                ..recruitment
+            },
+        ];
+
+        let resp =
+            create_prolog_recruitment_knowledge_base(&recruitments, NameRepresentation::Atom);
+
+        assert_eq!(
+            resp.iter()
+                .map(PrologTerm::to_fact)
+                .collect::<Vec<String>>(),
+            vec!["recruitable('a', 'b').".to_string()]
+        );
+    }
+}
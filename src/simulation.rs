@@ -0,0 +1,541 @@
+// smt-nocturne-prolog-kb-generator: A generates of a Prolog knowledge base
+// to describe demons, their fusion, and fusion mechanics for the game 
+// Shin Megami Tensei III: Nocturne.
+// Copyright (C) 2025  Bryan-Elliott Tam
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::fusion::*;
+use crate::model::*;
+use crate::planner::*;
+use rand::Rng;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+/// The assumed probability of an accident during a fusion performed at the full phase of Kagutsuchi.
+pub const ACCIDENT_PROBABILITY: f64 = 1.0 / 8.0;
+
+/// The mechanics of the fusion accidents assumed by the simulation.
+///
+/// The dataset does not describe the fusion accidents, so the default values are assumptions of this crate rather
+/// than values taken from the game: an accident only happens at the full phase of Kagutsuchi, with the probability
+/// `ACCIDENT_PROBABILITY` for a normal or a sacrificial fusion, and is a level shift of one rank up or down for half
+/// of the accidents and a random race for the other half.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AccidentAssumptions {
+    /// The probability of an accident during a normal fusion performed at the full phase of Kagutsuchi.
+    pub full_phase_probability: f64,
+    /// The probability of an accident during a normal fusion performed at another phase of Kagutsuchi.
+    pub other_phase_probability: f64,
+    /// The probability of an accident during a sacrificial fusion, only possible at the full phase of Kagutsuchi.
+    pub sacrificial_fusion_probability: f64,
+    /// The share of the accidents shifting the result one rank up or down, the other accidents giving a random
+    /// race to the result.
+    pub level_shift_share: f64,
+}
+
+impl Default for AccidentAssumptions {
+    fn default() -> Self {
+        Self {
+            full_phase_probability: ACCIDENT_PROBABILITY,
+            other_phase_probability: 0.0,
+            sacrificial_fusion_probability: ACCIDENT_PROBABILITY,
+            level_shift_share: 0.5,
+        }
+    }
+}
+
+impl AccidentAssumptions {
+    /// The probability of an accident during a normal fusion performed at the phase of Kagutsuchi.
+    pub fn normal_fusion_probability(&self, phase: KagutsuchiPhase) -> f64 {
+        if phase == KagutsuchiPhase::FULL {
+            self.full_phase_probability
+        } else {
+            self.other_phase_probability
+        }
+    }
+}
+
+/// An accident changing the result of a fusion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Accident<'a> {
+    /// The result is the demon `shift` ranks above the expected demon in its race,
+    /// or below it when `shift` is negative.
+    LevelShift(i64),
+    /// The result is the demon of the race that would be produced if the fusion produced this race.
+    RandomRace(&'a str),
+}
+
+/// The simulation of a normal fusion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SimulatedFusion<'a> {
+    /// The fusion without accident.
+    pub fusion: NormalFusion<'a>,
+    pub result: &'a Demon,
+    pub accident: Option<Accident<'a>>,
+}
+
+/// A possible result of a fusion with its probability.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Outcome<'a> {
+    pub demon: &'a Demon,
+    pub probability: f64,
+}
+
+/// The simulation of fusion accidents during a phase of Kagutsuchi.
+///
+/// An accident happens and changes the result as described by the `AccidentAssumptions` of the simulator.
+/// The simulated fusions are reproducible for a given seed.
+pub struct FusionSimulator<'a> {
+    engine: FusionEngine<'a>,
+    phase: KagutsuchiPhase,
+    assumptions: AccidentAssumptions,
+    rng: ChaCha8Rng,
+}
+
+impl<'a> FusionSimulator<'a> {
+    pub fn new(model: &'a Model, phase: KagutsuchiPhase, seed: u64) -> Self {
+        Self {
+            engine: FusionEngine::new(model).with_phase(phase),
+            phase,
+            assumptions: AccidentAssumptions::default(),
+            rng: ChaCha8Rng::seed_from_u64(seed),
+        }
+    }
+
+    /// Replace the default assumptions on the fusion accidents.
+    pub fn with_assumptions(mut self, assumptions: AccidentAssumptions) -> Self {
+        self.assumptions = assumptions;
+        self
+    }
+
+    /// Simulate the normal fusion of two demons, returning `None` when they cannot be fused.
+    pub fn simulate(
+        &mut self,
+        demon1: &'a Demon,
+        demon2: &'a Demon,
+    ) -> Option<SimulatedFusion<'a>> {
+        let fusion = NormalFusion {
+            demon1,
+            demon2,
+            result: self.engine.normal_fusion(demon1, demon2)?,
+        };
+        let mut simulated = SimulatedFusion {
+            fusion,
+            result: fusion.result,
+            accident: None,
+        };
+        if !self
+            .rng
+            .gen_bool(self.assumptions.normal_fusion_probability(self.phase))
+        {
+            return Some(simulated);
+        }
+
+        let accident = if self.rng.gen_bool(self.assumptions.level_shift_share) {
+            Accident::LevelShift(if self.rng.gen_bool(0.5) { 1 } else { -1 })
+        } else {
+            let races = self.accident_races(&fusion);
+            Accident::RandomRace(races[self.rng.gen_range(0..races.len())])
+        };
+        simulated.result = self.accident_result(&fusion, accident);
+        simulated.accident = Some(accident);
+        Some(simulated)
+    }
+
+    /// The probability distribution of the results of the normal fusion of two demons, sorted by decreasing
+    /// probability then by name.
+    ///
+    /// Returns an empty distribution when the demons cannot be fused.
+    pub fn outcome_distribution(&self, demon1: &'a Demon, demon2: &'a Demon) -> Vec<Outcome<'a>> {
+        let Some(result) = self.engine.normal_fusion(demon1, demon2) else {
+            return Vec::new();
+        };
+        self.outcomes(
+            NormalFusion {
+                demon1,
+                demon2,
+                result,
+            },
+            self.assumptions.normal_fusion_probability(self.phase),
+        )
+    }
+
+    /// The probability distribution of the results of the fusion given the probability `probability` of an
+    /// accident, sorted by decreasing probability then by name.
+    fn outcomes(&self, fusion: NormalFusion<'a>, probability: f64) -> Vec<Outcome<'a>> {
+        let result = fusion.result;
+        let races = self.accident_races(&fusion);
+
+        let level_shift_probability = probability * self.assumptions.level_shift_share / 2.0;
+        let mut accidents: Vec<(Accident, f64)> = vec![
+            (Accident::LevelShift(1), level_shift_probability),
+            (Accident::LevelShift(-1), level_shift_probability),
+        ];
+        let random_race_probability = probability * (1.0 - self.assumptions.level_shift_share);
+        accidents.extend(races.iter().map(|race| {
+            (
+                Accident::RandomRace(race),
+                random_race_probability / races.len() as f64,
+            )
+        }));
+
+        let mut outcomes = vec![Outcome {
+            demon: result,
+            probability: 1.0 - probability,
+        }];
+        for (accident, probability) in accidents {
+            let demon = self.accident_result(&fusion, accident);
+            match outcomes
+                .iter_mut()
+                .find(|outcome| outcome.demon.name == demon.name)
+            {
+                Some(outcome) => outcome.probability += probability,
+                None => outcomes.push(Outcome { demon, probability }),
+            }
+        }
+        outcomes.retain(|outcome| outcome.probability > 0.0);
+        outcomes.sort_by(|a, b| {
+            b.probability
+                .total_cmp(&a.probability)
+                .then(a.demon.name.cmp(&b.demon.name))
+        });
+        outcomes
+    }
+
+    /// The probability that an accident changes the result of at least one fusion of the plan.
+    ///
+    /// A sacrificial fusion has the accident probability of the sacrificial fusions, and the same outcomes as the
+    /// normal fusion of its two ingredients.
+    pub fn plan_risk(&self, plan: &Plan) -> f64 {
+        let success: f64 = plan
+            .actions
+            .iter()
+            .filter_map(|action| match action {
+                PlanAction::NormalFusion(fusion) => Some((
+                    *fusion,
+                    self.assumptions.normal_fusion_probability(self.phase),
+                )),
+                PlanAction::SacrificialFusion(fusion) => Some((
+                    NormalFusion {
+                        demon1: fusion.demon1,
+                        demon2: fusion.demon2,
+                        result: fusion.result,
+                    },
+                    self.assumptions.sacrificial_fusion_probability,
+                )),
+                PlanAction::Purchase(_) => None,
+            })
+            .map(|(fusion, probability)| {
+                self.outcomes(fusion, probability)
+                    .iter()
+                    .find(|outcome| outcome.demon.name == fusion.result.name)
+                    .map_or(0.0, |outcome| outcome.probability)
+            })
+            .product();
+        1.0 - success
+    }
+
+    /// The races that an accident can randomly give to the result of the fusion, which are the races having
+    /// a demon above the average level of the fused demons, or the race of the expected result without such race.
+    fn accident_races(&self, fusion: &NormalFusion<'a>) -> Vec<&'a str> {
+        let level_sum = fusion.demon1.base_level + fusion.demon2.base_level;
+        let races: Vec<&'a str> = self
+            .engine
+            .normal_fusion_races()
+            .into_iter()
+            .filter(|race| {
+                self.engine
+                    .lowest_demon_above_average(race, level_sum)
+                    .is_some()
+            })
+            .collect();
+        if races.is_empty() {
+            return vec![fusion.result.race.as_str()];
+        }
+        races
+    }
+
+    /// The result of the fusion after the accident, which is the expected result when the accident
+    /// leads to no demon.
+    fn accident_result(&self, fusion: &NormalFusion<'a>, accident: Accident) -> &'a Demon {
+        let level_sum = fusion.demon1.base_level + fusion.demon2.base_level;
+        match accident {
+            Accident::LevelShift(shift) => self.engine.shift_rank(fusion.result, shift),
+            Accident::RandomRace(race) => self.engine.lowest_demon_above_average(race, level_sum),
+        }
+        .unwrap_or(fusion.result)
+    }
+}
+
+/// The simulated fusions as text, one fusion per line, followed by the probability of each outcome.
+pub fn simulation_to_text(simulated: &[SimulatedFusion], outcomes: &[Outcome]) -> String {
+    let mut lines: Vec<String> = simulated
+        .iter()
+        .enumerate()
+        .map(|(i, simulated)| {
+            let accident = match simulated.accident {
+                Some(Accident::LevelShift(shift)) => format!(" (accident: level shift {shift:+})"),
+                Some(Accident::RandomRace(race)) => format!(" (accident: random race {race})"),
+                None => String::new(),
+            };
+            format!(
+                "{}. {} + {} -> {}{}",
+                i + 1,
+                simulated.fusion.demon1.name,
+                simulated.fusion.demon2.name,
+                simulated.result.name,
+                accident
+            )
+        })
+        .collect();
+    lines.push("outcomes:".to_string());
+    lines.extend(outcomes.iter().map(|outcome| {
+        format!(
+            "  {}: {:.2}%",
+            outcome.demon.name,
+            outcome.probability * 100.0
+        )
+    }));
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod fusion_simulator_test {
+    use super::*;
+    use crate::fusion::fusion_test_model::*;
+
+    fn total_probability(outcomes: &[Outcome]) -> f64 {
+        outcomes.iter().map(|outcome| outcome.probability).sum()
+    }
+
+    #[test]
+    fn should_not_have_accidents_outside_of_the_full_phase() {
+        let model = a_model();
+        let pixie = model.demon("Pixie").unwrap();
+        let kodama = model.demon("Kodama").unwrap();
+        let mut simulator = FusionSimulator::new(&model, KagutsuchiPhase::HALF, 0);
+
+        for _ in 0..100 {
+            let simulated = simulator.simulate(pixie, kodama).unwrap();
+            assert_eq!(simulated.accident, None);
+            assert_eq!(simulated.result, model.demon("Shikigami").unwrap());
+        }
+        assert_eq!(
+            simulator.outcome_distribution(pixie, kodama),
+            vec![Outcome {
+                demon: model.demon("Shikigami").unwrap(),
+                probability: 1.0,
+            }]
+        );
+    }
+
+    #[test]
+    fn should_reproduce_the_accidents_given_a_seed() {
+        let model = a_model();
+        let pixie = model.demon("Pixie").unwrap();
+        let kodama = model.demon("Kodama").unwrap();
+        let mut simulator1 = FusionSimulator::new(&model, KagutsuchiPhase::FULL, 42);
+        let mut simulator2 = FusionSimulator::new(&model, KagutsuchiPhase::FULL, 42);
+
+        let simulated1: Vec<SimulatedFusion> = (0..100)
+            .map(|_| simulator1.simulate(pixie, kodama).unwrap())
+            .collect();
+        let simulated2: Vec<SimulatedFusion> = (0..100)
+            .map(|_| simulator2.simulate(pixie, kodama).unwrap())
+            .collect();
+
+        assert_eq!(simulated1, simulated2);
+        assert!(simulated1
+            .iter()
+            .any(|simulated| simulated.accident.is_some()));
+        assert!(simulated1
+            .iter()
+            .any(|simulated| simulated.accident.is_none()));
+    }
+
+    #[test]
+    fn should_simulate_a_result_of_the_distribution() {
+        let model = a_model();
+        let pixie = model.demon("Pixie").unwrap();
+        let kodama = model.demon("Kodama").unwrap();
+        let mut simulator = FusionSimulator::new(&model, KagutsuchiPhase::FULL, 7);
+        let outcomes = simulator.outcome_distribution(pixie, kodama);
+
+        for _ in 0..100 {
+            let simulated = simulator.simulate(pixie, kodama).unwrap();
+            assert!(outcomes
+                .iter()
+                .any(|outcome| outcome.demon == simulated.result));
+        }
+    }
+
+    #[test]
+    fn should_return_the_distribution_of_the_outcomes_at_full_phase() {
+        let model = a_model();
+        let simulator = FusionSimulator::new(&model, KagutsuchiPhase::FULL, 0);
+
+        let outcomes = simulator.outcome_distribution(
+            model.demon("Pixie").unwrap(),
+            model.demon("Kodama").unwrap(),
+        );
+
+        assert_eq!(outcomes[0].demon, model.demon("Shikigami").unwrap());
+        assert!(outcomes[0].probability > 1.0 - ACCIDENT_PROBABILITY);
+        assert!(outcomes
+            .iter()
+            .any(|outcome| outcome.demon == model.demon("Ikusa").unwrap()));
+        assert!((total_probability(&outcomes) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn should_return_an_empty_distribution_given_demons_that_cannot_be_fused() {
+        let model = a_model();
+        let simulator = FusionSimulator::new(&model, KagutsuchiPhase::FULL, 0);
+
+        let outcomes = simulator
+            .outcome_distribution(model.demon("Pixie").unwrap(), model.demon("Uzume").unwrap());
+
+        assert!(outcomes.is_empty());
+    }
+
+    #[test]
+    fn should_return_the_risk_of_a_plan() {
+        let model = a_model();
+        let plan = Plan {
+            actions: vec![PlanAction::NormalFusion(NormalFusion {
+                demon1: model.demon("Kodama").unwrap(),
+                demon2: model.demon("Pixie").unwrap(),
+                result: model.demon("Shikigami").unwrap(),
+            })],
+        };
+
+        let simulator = FusionSimulator::new(&model, KagutsuchiPhase::NEW, 0);
+        assert_eq!(simulator.plan_risk(&plan), 0.0);
+
+        let simulator = FusionSimulator::new(&model, KagutsuchiPhase::FULL, 0);
+        let risk = simulator.plan_risk(&plan);
+        assert!(risk > 0.0);
+        assert!(risk < ACCIDENT_PROBABILITY);
+    }
+
+    #[test]
+    fn should_return_the_risk_of_a_sacrificial_fusion() {
+        let model = a_model();
+        let plan = Plan {
+            actions: vec![PlanAction::SacrificialFusion(SacrificialFusion {
+                demon1: model.demon("Kodama").unwrap(),
+                demon2: model.demon("Pixie").unwrap(),
+                sacrifice: model.demon("Oni").unwrap(),
+                result: model.demon("Shikigami").unwrap(),
+            })],
+        };
+
+        let simulator = FusionSimulator::new(&model, KagutsuchiPhase::FULL, 0);
+        assert!(simulator.plan_risk(&plan) > 0.0);
+
+        let simulator = FusionSimulator::new(&model, KagutsuchiPhase::FULL, 0).with_assumptions(
+            AccidentAssumptions {
+                sacrificial_fusion_probability: 0.0,
+                ..AccidentAssumptions::default()
+            },
+        );
+        assert_eq!(simulator.plan_risk(&plan), 0.0);
+    }
+
+    #[test]
+    fn should_follow_the_assumptions_on_the_accidents() {
+        let model = a_model();
+        let pixie = model.demon("Pixie").unwrap();
+        let kodama = model.demon("Kodama").unwrap();
+        let assumptions = AccidentAssumptions {
+            other_phase_probability: 0.5,
+            level_shift_share: 1.0,
+            ..AccidentAssumptions::default()
+        };
+        let mut simulator =
+            FusionSimulator::new(&model, KagutsuchiPhase::HALF, 0).with_assumptions(assumptions);
+
+        assert_eq!(
+            simulator.outcome_distribution(pixie, kodama),
+            vec![
+                Outcome {
+                    demon: model.demon("Shikigami").unwrap(),
+                    probability: 0.75,
+                },
+                Outcome {
+                    demon: model.demon("Ikusa").unwrap(),
+                    probability: 0.25,
+                },
+            ]
+        );
+        for _ in 0..100 {
+            let simulated = simulator.simulate(pixie, kodama).unwrap();
+            assert!(matches!(
+                simulated.accident,
+                None | Some(Accident::LevelShift(_))
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod simulation_to_text_test {
+    use super::*;
+    use crate::fusion::fusion_test_model::*;
+
+    #[test]
+    fn should_return_the_simulated_fusions_and_the_outcomes() {
+        let model = a_model();
+        let fusion = NormalFusion {
+            demon1: model.demon("Pixie").unwrap(),
+            demon2: model.demon("Kodama").unwrap(),
+            result: model.demon("Shikigami").unwrap(),
+        };
+        let simulated = vec![
+            SimulatedFusion {
+                fusion,
+                result: fusion.result,
+                accident: None,
+            },
+            SimulatedFusion {
+                fusion,
+                result: model.demon("Ikusa").unwrap(),
+                accident: Some(Accident::LevelShift(1)),
+            },
+            SimulatedFusion {
+                fusion,
+                result: model.demon("Pixie").unwrap(),
+                accident: Some(Accident::RandomRace("Fairy")),
+            },
+        ];
+        let outcomes = vec![
+            Outcome {
+                demon: model.demon("Shikigami").unwrap(),
+                probability: 0.875,
+            },
+            Outcome {
+                demon: model.demon("Ikusa").unwrap(),
+                probability: 0.125,
+            },
+        ];
+
+        let resp = simulation_to_text(&simulated, &outcomes);
+
+        assert_eq!(
+            resp,
+            "1. Pixie + Kodama -> Shikigami\n2. Pixie + Kodama -> Ikusa (accident: level shift +1)\n3. Pixie + Kodama -> Pixie (accident: random race Fairy)\noutcomes:\n  Shikigami: 87.50%\n  Ikusa: 12.50%"
+        );
+    }
+}
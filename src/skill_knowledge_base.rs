@@ -0,0 +1,388 @@
+// smt-nocturne-prolog-kb-generator: A generates of a Prolog knowledge base
+// to describe demons, their fusion, and fusion mechanics for the game 
+// Shin Megami Tensei III: Nocturne.
+// Copyright (C) 2025  Bryan-Elliott Tam
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::dataset::Dataset;
+use crate::error::ErrorAmbiguousSkillCost;
+use crate::model::*;
+use crate::prolog::*;
+use crate::util::*;
+use anyhow::Error;
+use oxigraph::sparql::QuerySolution;
+
+/// Query the Shin Megami Tensei Nocture skills from a dataset.
+pub fn query_skills(dataset: &Dataset) -> Result<Vec<Skill>, Error> {
+    query_model(dataset, GET_SKILL_QUERY, skill_from_solution)
+}
+
+/// Query the skills learned by the Shin Megami Tensei Nocture demons from a dataset.
+pub fn query_learned_skills(dataset: &Dataset) -> Result<Vec<LearnedSkill>, Error> {
+    query_model(
+        dataset,
+        GET_LEARNED_SKILL_QUERY,
+        learned_skill_from_solution,
+    )
+}
+
+/// create a prolog knowledge based containing the skills of Shin Megami Tensei Nocture demons.
+pub fn create_prolog_skill_knowledge_base(
+    skills: &[Skill],
+    name_representation: NameRepresentation,
+) -> Vec<PrologTerm> {
+    create_prolog_knowledge_base(skills, |skill| {
+        let cost = match skill.cost {
+            Some(SkillCost::Mp(cost)) => {
+                PrologTerm::compound("mp", vec![PrologTerm::Integer(cost)])
+            }
+            Some(SkillCost::Hp(cost)) => {
+                PrologTerm::compound("hp", vec![PrologTerm::Integer(cost)])
+            }
+            None => PrologTerm::Atom("none".to_string()),
+        };
+        let power = skill
+            .power
+            .map_or(PrologTerm::Atom("none".to_string()), PrologTerm::Integer);
+        PrologTerm::compound(
+            "skill",
+            vec![
+                name_representation.term(&skill.name),
+                name_representation.term(&skill.element),
+                cost,
+                power,
+                name_representation.term(&skill.target),
+            ],
+        )
+    })
+}
+
+/// create a prolog knowledge based containing the categories of the skills of Shin Megami Tensei Nocture demons.
+pub fn create_prolog_skill_category_knowledge_base(
+    skills: &[Skill],
+    name_representation: NameRepresentation,
+) -> Vec<PrologTerm> {
+    create_prolog_knowledge_base(skills, |skill| {
+        PrologTerm::compound(
+            "skill_category",
+            vec![
+                name_representation.term(&skill.name),
+                name_representation.term(&skill.category),
+            ],
+        )
+    })
+}
+
+/// create a prolog knowledge based containing the skills learned by Shin Megami Tensei Nocture demons.
+pub fn create_prolog_learned_skill_knowledge_base(
+    learned_skills: &[LearnedSkill],
+    name_representation: NameRepresentation,
+) -> Vec<PrologTerm> {
+    create_prolog_knowledge_base(learned_skills, |learned| {
+        PrologTerm::compound(
+            "learns",
+            vec![
+                name_representation.term(&learned.demon),
+                name_representation.term(&learned.skill),
+                PrologTerm::Integer(learned.level),
+            ],
+        )
+    })
+}
+
+fn skill_from_solution(solution_map: QuerySolution) -> Result<Skill, Error> {
+    let name = solution_variable(&solution_map, "name")?;
+    let element = solution_variable(&solution_map, "element")?;
+    let category = solution_variable(&solution_map, "category")?;
+    let target = solution_variable(&solution_map, "target")?;
+    let name = literal_string_to_string(name, "name")?;
+
+    let mp_cost = solution_map
+        .get("mpCost")
+        .map(|mp_cost| literal_integer_to_i64(mp_cost, "mpCost"))
+        .transpose()?;
+    let hp_cost = solution_map
+        .get("hpCost")
+        .map(|hp_cost| literal_integer_to_i64(hp_cost, "hpCost"))
+        .transpose()?;
+    let cost = match (mp_cost, hp_cost) {
+        (Some(_), Some(_)) => return Err(ErrorAmbiguousSkillCost { skill: name }.into()),
+        (Some(cost), None) => Some(SkillCost::Mp(cost)),
+        (None, Some(cost)) => Some(SkillCost::Hp(cost)),
+        (None, None) => None,
+    };
+
+    Ok(Skill {
+        element: literal_string_to_string(element, "element")?,
+        category: literal_string_to_string(category, "category")?,
+        cost,
+        power: solution_map
+            .get("power")
+            .map(|power| literal_integer_to_i64(power, "power"))
+            .transpose()?,
+        target: literal_string_to_string(target, "target")?,
+        name,
+    })
+}
+
+fn learned_skill_from_solution(solution_map: QuerySolution) -> Result<LearnedSkill, Error> {
+    let demon = solution_variable(&solution_map, "demon")?;
+    let skill = solution_variable(&solution_map, "skill")?;
+    let level = solution_variable(&solution_map, "level")?;
+
+    Ok(LearnedSkill {
+        demon: literal_string_to_string(demon, "demon")?,
+        skill: literal_string_to_string(skill, "skill")?,
+        level: literal_integer_to_i64(level, "level")?,
+    })
+}
+
+const GET_SKILL_QUERY: &str = "
+PREFIX vocab: <https://constraintautomaton.github.io/smt-nocture-db-to-rdf/vocabulary.ttl#>
+PREFIX schema: <https://schema.org/>
+
+SELECT ?name ?element ?category ?mpCost ?hpCost ?power ?target WHERE {
+    ?skill a vocab:Skill ;
+        schema:name ?name ;
+        vocab:skillElement ?element ;
+        vocab:skillCategory ?category ;
+        vocab:skillTarget ?target .
+
+    OPTIONAL { ?skill vocab:mpCost ?mpCost . }
+    OPTIONAL { ?skill vocab:hpCost ?hpCost . }
+    OPTIONAL { ?skill vocab:skillPower ?power . }
+}";
+
+const GET_LEARNED_SKILL_QUERY: &str = "
+PREFIX vocab: <https://constraintautomaton.github.io/smt-nocture-db-to-rdf/vocabulary.ttl#>
+PREFIX schema: <https://schema.org/>
+
+SELECT ?demon ?skill ?level WHERE {
+    [] vocab:learnedBy ?demonIri ;
+        vocab:learnedSkill ?skillIri ;
+        vocab:learnLevel ?level .
+
+    ?demonIri schema:name ?demon .
+    ?skillIri schema:name ?skill .
+}";
+
+#[cfg(test)]
+mod skill_from_solution_test {
+    use super::*;
+    use oxigraph::model::*;
+
+    #[test]
+    fn should_return_an_error_given_the_power_is_not_an_integer() -> Result<(), Error> {
+        let solution_map: QuerySolution = QuerySolution::from((
+            vec![
+                Variable::new("name")?,
+                Variable::new("element")?,
+                Variable::new("category")?,
+                Variable::new("power")?,
+                Variable::new("target")?,
+            ],
+            vec![
+                Term::Literal(Literal::from("Agi")).into(),
+                Term::Literal(Literal::from("Fire")).into(),
+                Term::Literal(Literal::from("Fire")).into(),
+                Term::Literal(Literal::from("a")).into(),
+                Term::Literal(Literal::from("single enemy")).into(),
+            ],
+        ));
+
+        let res = skill_from_solution(solution_map);
+
+        assert_eq!(
+            res.err().map(|error| error.to_string()),
+            Some("the value of the variable 'power' is not an integer".to_string())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_an_error_given_both_an_mp_and_an_hp_cost() -> Result<(), Error> {
+        let solution_map: QuerySolution = QuerySolution::from((
+            vec![
+                Variable::new("name")?,
+                Variable::new("element")?,
+                Variable::new("category")?,
+                Variable::new("mpCost")?,
+                Variable::new("hpCost")?,
+                Variable::new("target")?,
+            ],
+            vec![
+                Term::Literal(Literal::from("Agi")).into(),
+                Term::Literal(Literal::from("Fire")).into(),
+                Term::Literal(Literal::from("Fire")).into(),
+                Term::Literal(Literal::from(3)).into(),
+                Term::Literal(Literal::from(10)).into(),
+                Term::Literal(Literal::from("single enemy")).into(),
+            ],
+        ));
+
+        let res = skill_from_solution(solution_map);
+
+        assert_eq!(
+            res.err().map(|error| error.to_string()),
+            Some("the skill 'Agi' has both an MP cost and an HP cost".to_string())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_a_skill_given_a_valid_solution_map() -> Result<(), Error> {
+        let solution_map: QuerySolution = QuerySolution::from((
+            vec![
+                Variable::new("name")?,
+                Variable::new("element")?,
+                Variable::new("category")?,
+                Variable::new("hpCost")?,
+                Variable::new("power")?,
+                Variable::new("target")?,
+            ],
+            vec![
+                Term::Literal(Literal::from("Lunge")).into(),
+                Term::Literal(Literal::from("Phys")).into(),
+                Term::Literal(Literal::from("Physical")).into(),
+                Term::Literal(Literal::from(8)).into(),
+                Term::Literal(Literal::from(30)).into(),
+                Term::Literal(Literal::from("single enemy")).into(),
+            ],
+        ));
+
+        let res = skill_from_solution(solution_map)?;
+
+        assert_eq!(
+            res,
+            Skill {
+                name: "Lunge".to_string(),
+                element: "Phys".to_string(),
+                category: "Physical".to_string(),
+                cost: Some(SkillCost::Hp(8)),
+                power: Some(30),
+                target: "single enemy".to_string(),
+            }
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod learned_skill_from_solution_test {
+    use super::*;
+    use oxigraph::model::*;
+
+    #[test]
+    fn should_return_an_error_given_the_level_is_not_in_the_solution_map() -> Result<(), Error> {
+        let solution_map: QuerySolution = QuerySolution::from((
+            vec![Variable::new("demon")?, Variable::new("skill")?],
+            vec![
+                Term::Literal(Literal::from("a")).into(),
+                Term::Literal(Literal::from("b")).into(),
+            ],
+        ));
+
+        let res = learned_skill_from_solution(solution_map);
+
+        assert_eq!(
+            res.err().map(|error| error.to_string()),
+            Some("the variable 'level' does not exist in the solution map".to_string())
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod query_skills_test {
+    use super::*;
+    use std::collections::HashSet;
+    use std::path::PathBuf;
+
+    fn a_dataset() -> Result<Dataset, Error> {
+        let demon_file_path = PathBuf::from("./test_files/test_valid_demon.ttl");
+        let skill_file_path = PathBuf::from("./test_files/test_valid_skill.ttl");
+        Dataset::load(&[&demon_file_path, &skill_file_path])
+    }
+
+    #[test]
+    fn should_return_a_prolog_skill_knowledge_base() -> Result<(), Error> {
+        let expected_knowledge_base: HashSet<String> = vec![
+            "skill('Zan', 'Force', mp(3), 40, 'single enemy').".to_string(),
+            "skill('Lunge', 'Phys', hp(8), 30, 'single enemy').".to_string(),
+            "skill('Tarunda', 'Almighty', mp(8), none, 'all enemies').".to_string(),
+            "skill('Force Boost', 'Force', none, none, 'self').".to_string(),
+        ]
+        .into_iter()
+        .collect();
+
+        let skills = query_skills(&a_dataset()?)?;
+        let resp = create_prolog_skill_knowledge_base(&skills, NameRepresentation::Atom);
+
+        assert_eq!(
+            resp.iter()
+                .map(PrologTerm::to_fact)
+                .collect::<HashSet<String>>(),
+            expected_knowledge_base
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_a_prolog_skill_category_knowledge_base() -> Result<(), Error> {
+        let expected_knowledge_base: HashSet<String> = vec![
+            "skill_category('Zan', 'Force').".to_string(),
+            "skill_category('Lunge', 'Physical').".to_string(),
+            "skill_category('Tarunda', 'Support').".to_string(),
+            "skill_category('Force Boost', 'Passive').".to_string(),
+        ]
+        .into_iter()
+        .collect();
+
+        let skills = query_skills(&a_dataset()?)?;
+        let resp = create_prolog_skill_category_knowledge_base(&skills, NameRepresentation::Atom);
+
+        assert_eq!(
+            resp.iter()
+                .map(PrologTerm::to_fact)
+                .collect::<HashSet<String>>(),
+            expected_knowledge_base
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_a_prolog_learned_skill_knowledge_base() -> Result<(), Error> {
+        let expected_knowledge_base: HashSet<String> = vec![
+            "learns('Aeros', 'Zan', 11).".to_string(),
+            "learns('Aeros', 'Tarunda', 13).".to_string(),
+            "learns('Aeros', 'Force Boost', 14).".to_string(),
+            "learns('Abaddon', 'Lunge', 69).".to_string(),
+        ]
+        .into_iter()
+        .collect();
+
+        let learned_skills = query_learned_skills(&a_dataset()?)?;
+        let resp =
+            create_prolog_learned_skill_knowledge_base(&learned_skills, NameRepresentation::Atom);
+
+        assert_eq!(
+            resp.iter()
+                .map(PrologTerm::to_fact)
+                .collect::<HashSet<String>>(),
+            expected_knowledge_base
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,479 @@
+// smt-nocturne-prolog-kb-generator: A generates of a Prolog knowledge base
+// to describe demons, their fusion, and fusion mechanics for the game 
+// Shin Megami Tensei III: Nocturne.
+// Copyright (C) 2025  Bryan-Elliott Tam
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::dataset::Dataset;
+use crate::error::ErrorInconsistentIngredientCount;
+use crate::model::*;
+use crate::prolog::*;
+use crate::util::*;
+use anyhow::Error;
+use oxigraph::model::Term;
+use oxigraph::sparql::QuerySolution;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+/// Query the Shin Megami Tensei Nocture special fusion recipes from a dataset.
+///
+/// The recipes are sorted by the name of their resulting demon.
+/// A demon used twice by a recipe is given by two ingredient nodes with its name, and a recipe with many phases of
+/// Kagutsuchi can be performed during any of them.
+pub fn query_special_fusion_recipes(dataset: &Dataset) -> Result<Vec<SpecialFusionRecipe>, Error> {
+    let rows = query_model(
+        dataset,
+        GET_SPECIAL_FUSION_QUERY,
+        special_fusion_row_from_solution,
+    )?;
+
+    let mut recipes: Vec<(SpecialFusionRecipe, i64)> = Vec::new();
+    let mut recipe_indexes: HashMap<String, usize> = HashMap::new();
+    // The rows are repeated for every phase of a recipe, so an ingredient is identified by its node.
+    let mut ingredient_nodes: HashSet<(usize, String)> = HashSet::new();
+    for row in rows {
+        let index = *recipe_indexes.entry(row.recipe.clone()).or_insert_with(|| {
+            recipes.push((
+                SpecialFusionRecipe {
+                    iri: row.recipe.clone(),
+                    result: row.result.clone(),
+                    ingredients: Vec::new(),
+                    kagutsuchi_phases: Vec::new(),
+                    prerequisites: Vec::new(),
+                },
+                row.count,
+            ));
+            recipes.len() - 1
+        });
+        let (recipe, _) = &mut recipes[index];
+        if let Some((node, ingredient)) = row.ingredient {
+            if ingredient_nodes.insert((index, node)) {
+                recipe.ingredients.push(ingredient);
+            }
+        }
+        if let Some(phase) = row.kagutsuchi_phase {
+            recipe.kagutsuchi_phases.push(phase);
+        }
+        if let Some(prerequisite) = row.prerequisite {
+            recipe.prerequisites.push(prerequisite);
+        }
+    }
+
+    let mut special_fusion_recipes = Vec::with_capacity(recipes.len());
+    for (mut recipe, count) in recipes {
+        recipe.ingredients.sort();
+        recipe.kagutsuchi_phases.sort();
+        recipe.kagutsuchi_phases.dedup();
+        recipe.prerequisites.sort();
+        recipe.prerequisites.dedup();
+        if recipe.ingredients.len() as i64 != count {
+            return Err(ErrorInconsistentIngredientCount {
+                result: recipe.result,
+                count,
+                ingredients: recipe.ingredients.len(),
+            }
+            .into());
+        }
+        special_fusion_recipes.push(recipe);
+    }
+    special_fusion_recipes.sort_by(|a, b| a.result.cmp(&b.result).then(a.iri.cmp(&b.iri)));
+    Ok(special_fusion_recipes)
+}
+
+/// create a prolog knowledge based containing the special fusion recipes of Shin Megami Tensei Nocture demons.
+///
+/// A recipe with many phases of Kagutsuchi is described by a fact per phase.
+pub fn create_prolog_special_fusion_knowledge_base(
+    special_fusion_recipes: &[SpecialFusionRecipe],
+    name_representation: NameRepresentation,
+) -> Vec<PrologTerm> {
+    special_fusion_recipes
+        .iter()
+        .flat_map(|recipe| {
+            let phases: Vec<Option<KagutsuchiPhase>> = if recipe.kagutsuchi_phases.is_empty() {
+                vec![None]
+            } else {
+                recipe.kagutsuchi_phases.iter().copied().map(Some).collect()
+            };
+            phases
+                .into_iter()
+                .map(|phase| generate_a_prolog_fact(recipe, phase, name_representation))
+        })
+        .collect()
+}
+
+/// A solution of the special fusion query, describing a single ingredient or prerequisite of a recipe.
+struct SpecialFusionRow {
+    recipe: String,
+    result: String,
+    count: i64,
+    kagutsuchi_phase: Option<KagutsuchiPhase>,
+    /// The node of the ingredient with the name of its demon.
+    ingredient: Option<(String, String)>,
+    prerequisite: Option<String>,
+}
+
+fn special_fusion_row_from_solution(
+    solution_map: QuerySolution,
+) -> Result<SpecialFusionRow, Error> {
+    let recipe = solution_variable(&solution_map, "recipe")?;
+    let result = solution_variable(&solution_map, "result")?;
+    let count = solution_variable(&solution_map, "count")?;
+
+    Ok(SpecialFusionRow {
+        recipe: match recipe {
+            Term::NamedNode(iri) => iri.as_str().to_string(),
+            term => term.to_string(),
+        },
+        result: literal_string_to_string(result, "result")?,
+        count: literal_integer_to_i64(count, "count")?,
+        kagutsuchi_phase: solution_map
+            .get("phase")
+            .map(|phase| literal_kagutsuchi_phase(phase, "phase"))
+            .transpose()?,
+        ingredient: solution_map
+            .get("ingredient")
+            .map(|ingredient| {
+                let node = solution_variable(&solution_map, "ingredientIri")?;
+                Ok::<_, Error>((
+                    node.to_string(),
+                    literal_string_to_string(ingredient, "ingredient")?,
+                ))
+            })
+            .transpose()?,
+        prerequisite: solution_map
+            .get("prerequisite")
+            .map(|prerequisite| literal_string_to_string(prerequisite, "prerequisite"))
+            .transpose()?,
+    })
+}
+
+fn generate_a_prolog_fact(
+    recipe: &SpecialFusionRecipe,
+    kagutsuchi_phase: Option<KagutsuchiPhase>,
+    name_representation: NameRepresentation,
+) -> PrologTerm {
+    let mut conditions = Vec::new();
+    if let Some(phase) = kagutsuchi_phase {
+        conditions.push(PrologTerm::compound(
+            "kagutsuchi_phase",
+            vec![PrologTerm::Integer(phase.value().into())],
+        ));
+    }
+    conditions.extend(recipe.prerequisites.iter().map(|prerequisite| {
+        PrologTerm::compound(
+            "prerequisite",
+            vec![PrologTerm::String(prerequisite.clone())],
+        )
+    }));
+
+    PrologTerm::compound(
+        "special_fusion",
+        vec![
+            name_representation.term(&recipe.result),
+            PrologTerm::List(
+                recipe
+                    .ingredients
+                    .iter()
+                    .map(|ingredient| name_representation.term(ingredient))
+                    .collect(),
+            ),
+            PrologTerm::List(conditions),
+        ],
+    )
+}
+
+const GET_SPECIAL_FUSION_QUERY: &str = "
+PREFIX vocab: <https://constraintautomaton.github.io/smt-nocture-db-to-rdf/vocabulary.ttl#>
+PREFIX schema: <https://schema.org/>
+
+SELECT ?recipe ?result ?count ?phase ?ingredientIri ?ingredient ?prerequisite WHERE {
+    ?recipe a vocab:SpecialFusionRecipe;
+        vocab:fusionResult ?resultIri;
+        vocab:ingredientCount ?count.
+
+    ?resultIri schema:name ?result .
+
+    OPTIONAL { ?recipe vocab:kagutsuchiPhase ?phase . }
+
+    {
+        ?recipe vocab:hasIngredient ?ingredientIri .
+        ?ingredientIri schema:name ?ingredient .
+    } UNION {
+        ?recipe vocab:prerequisite ?prerequisite .
+    }
+}";
+
+#[cfg(test)]
+mod special_fusion_row_from_solution_test {
+    use super::*;
+    use oxigraph::model::*;
+
+    #[test]
+    fn should_return_a_row_without_the_optional_variables() -> Result<(), Error> {
+        let solution_map: QuerySolution = QuerySolution::from((
+            vec![
+                Variable::new("recipe")?,
+                Variable::new("result")?,
+                Variable::new("count")?,
+            ],
+            vec![
+                Term::NamedNode(NamedNode::new("http://example.com/a")?).into(),
+                Term::Literal(Literal::from("Hope")).into(),
+                Term::Literal(Literal::from(2)).into(),
+            ],
+        ));
+
+        let row = special_fusion_row_from_solution(solution_map)?;
+
+        assert_eq!(row.recipe, "http://example.com/a".to_string());
+        assert_eq!(row.result, "Hope".to_string());
+        assert_eq!(row.count, 2);
+        assert_eq!(row.kagutsuchi_phase, None);
+        assert_eq!(row.ingredient, None);
+        assert_eq!(row.prerequisite, None);
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_an_error_given_the_phase_is_not_an_integer() -> Result<(), Error> {
+        let solution_map: QuerySolution = QuerySolution::from((
+            vec![
+                Variable::new("recipe")?,
+                Variable::new("result")?,
+                Variable::new("count")?,
+                Variable::new("phase")?,
+            ],
+            vec![
+                Term::NamedNode(NamedNode::new("http://example.com/a")?).into(),
+                Term::Literal(Literal::from("Hope")).into(),
+                Term::Literal(Literal::from(2)).into(),
+                Term::Literal(Literal::from("full")).into(),
+            ],
+        ));
+
+        let res = special_fusion_row_from_solution(solution_map);
+
+        assert_eq!(
+            res.err().map(|error| error.to_string()),
+            Some("the value of the variable 'phase' is not an integer".to_string())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_an_error_given_the_count_is_not_in_the_solution_map() -> Result<(), Error> {
+        let solution_map: QuerySolution = QuerySolution::from((
+            vec![Variable::new("recipe")?, Variable::new("result")?],
+            vec![
+                Term::NamedNode(NamedNode::new("http://example.com/a")?).into(),
+                Term::Literal(Literal::from("Hope")).into(),
+            ],
+        ));
+
+        let res = special_fusion_row_from_solution(solution_map);
+
+        assert_eq!(
+            res.err().map(|error| error.to_string()),
+            Some("the variable 'count' does not exist in the solution map".to_string())
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod generate_a_prolog_fact_test {
+    use super::*;
+
+    #[test]
+    fn should_return_a_fact_with_the_conditions() {
+        let recipe = SpecialFusionRecipe {
+            iri: "http://example.com/a".to_string(),
+            result: "Abaddon".to_string(),
+            ingredients: vec!["Aeros".to_string(), "Hope".to_string()],
+            kagutsuchi_phases: vec![KagutsuchiPhase::FULL],
+            prerequisites: vec!["Defeat Matador".to_string()],
+        };
+
+        let resp = generate_a_prolog_fact(
+            &recipe,
+            Some(KagutsuchiPhase::FULL),
+            NameRepresentation::Atom,
+        );
+
+        assert_eq!(
+            resp.to_fact(),
+            "special_fusion('Abaddon', ['Aeros', 'Hope'], [kagutsuchi_phase(8), prerequisite(\"Defeat Matador\")]).".to_string()
+        );
+    }
+
+    #[test]
+    fn should_return_a_fact_without_conditions() {
+        let recipe = SpecialFusionRecipe {
+            iri: "http://example.com/a".to_string(),
+            result: "Hope".to_string(),
+            ingredients: vec!["Aeros".to_string(), "Abaddon".to_string()],
+            kagutsuchi_phases: vec![],
+            prerequisites: vec![],
+        };
+
+        let resp = generate_a_prolog_fact(&recipe, None, NameRepresentation::String);
+
+        assert_eq!(
+            resp.to_fact(),
+            "special_fusion(\"Hope\", [\"Aeros\", \"Abaddon\"], []).".to_string()
+        );
+    }
+}
+
+#[cfg(test)]
+mod create_prolog_special_fusion_knowledge_base_test {
+    use super::*;
+
+    #[test]
+    fn should_return_a_fact_per_phase() {
+        let recipe = SpecialFusionRecipe {
+            iri: "http://example.com/a".to_string(),
+            result: "Hope".to_string(),
+            ingredients: vec!["Aeros".to_string(), "Aeros".to_string()],
+            kagutsuchi_phases: vec![KagutsuchiPhase::NEW, KagutsuchiPhase::FULL],
+            prerequisites: vec![],
+        };
+
+        let resp = create_prolog_special_fusion_knowledge_base(&[recipe], NameRepresentation::Atom);
+
+        assert_eq!(
+            resp.iter()
+                .map(PrologTerm::to_fact)
+                .collect::<Vec<String>>(),
+            vec![
+                "special_fusion('Hope', ['Aeros', 'Aeros'], [kagutsuchi_phase(0)]).".to_string(),
+                "special_fusion('Hope', ['Aeros', 'Aeros'], [kagutsuchi_phase(8)]).".to_string(),
+            ]
+        );
+    }
+}
+
+#[cfg(test)]
+mod query_special_fusion_recipes_test {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn should_return_the_recipes() -> Result<(), Error> {
+        let demon_file_path = PathBuf::from("./test_files/test_valid_demon.ttl");
+        let special_fusion_file_path = PathBuf::from("./test_files/test_valid_special_fusion.ttl");
+
+        let recipes = Dataset::load(&[&demon_file_path, &special_fusion_file_path])
+            .and_then(|dataset| query_special_fusion_recipes(&dataset))?;
+
+        assert_eq!(recipes.len(), 2);
+        assert_eq!(recipes[0].result, "Abaddon".to_string());
+        assert_eq!(
+            recipes[0].ingredients,
+            vec![
+                "Aeros".to_string(),
+                "Hope".to_string(),
+                "Matador".to_string()
+            ]
+        );
+        assert_eq!(recipes[0].kagutsuchi_phases, vec![KagutsuchiPhase::FULL]);
+        assert_eq!(
+            recipes[0].prerequisites,
+            vec![
+                "Cleared the Labyrinth of Amala".to_string(),
+                "Defeat Matador".to_string()
+            ]
+        );
+        assert_eq!(recipes[1].result, "Hope".to_string());
+        assert_eq!(
+            recipes[1].ingredients,
+            vec!["Abaddon".to_string(), "Aeros".to_string()]
+        );
+        assert!(recipes[1].kagutsuchi_phases.is_empty());
+        assert!(recipes[1].prerequisites.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn should_keep_an_ingredient_used_twice() -> Result<(), Error> {
+        let special_fusion_file_path =
+            PathBuf::from("./test_files/test_special_fusion_with_a_repeated_ingredient.ttl");
+
+        let recipes = Dataset::load(&[&special_fusion_file_path])
+            .and_then(|dataset| query_special_fusion_recipes(&dataset))?;
+
+        assert_eq!(recipes.len(), 1);
+        assert_eq!(
+            recipes[0].ingredients,
+            vec![
+                "Aeros".to_string(),
+                "Aeros".to_string(),
+                "Matador".to_string()
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_every_phase_of_a_recipe() -> Result<(), Error> {
+        let special_fusion_file_path =
+            PathBuf::from("./test_files/test_special_fusion_with_many_phases.ttl");
+
+        let recipes = Dataset::load(&[&special_fusion_file_path])
+            .and_then(|dataset| query_special_fusion_recipes(&dataset))?;
+
+        assert_eq!(recipes.len(), 1);
+        assert_eq!(
+            recipes[0].ingredients,
+            vec!["Aeros".to_string(), "Matador".to_string()]
+        );
+        assert_eq!(
+            recipes[0].kagutsuchi_phases,
+            vec![KagutsuchiPhase::NEW, KagutsuchiPhase::FULL]
+        );
+        assert_eq!(recipes[0].prerequisites, vec!["Defeat Matador".to_string()]);
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_an_error_given_an_inconsistent_ingredient_count() {
+        let demon_file_path = PathBuf::from("./test_files/test_valid_demon.ttl");
+        let special_fusion_file_path =
+            PathBuf::from("./test_files/test_invalid_special_fusion.ttl");
+
+        let res = Dataset::load(&[&demon_file_path, &special_fusion_file_path])
+            .and_then(|dataset| query_special_fusion_recipes(&dataset));
+
+        assert_eq!(
+            res.err().map(|error| error.to_string()),
+            Some(
+                "the special fusion of 'Hope' expects 3 ingredients but has 2 ingredients"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn should_return_no_recipe_given_a_dataset_without_recipes() -> Result<(), Error> {
+        let demon_file_path = PathBuf::from("./test_files/test_valid_demon.ttl");
+
+        let recipes = Dataset::load(&[&demon_file_path])
+            .and_then(|dataset| query_special_fusion_recipes(&dataset))?;
+
+        assert!(recipes.is_empty());
+        Ok(())
+    }
+}
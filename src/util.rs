@@ -16,67 +16,67 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
+use crate::dataset::Dataset;
 use crate::error::*;
+use crate::model::KagutsuchiPhase;
+use crate::prolog::PrologTerm;
 use anyhow::Error;
-use oxigraph::io::RdfSyntaxError;
+use oxigraph::model::vocab::xsd;
 use oxigraph::model::*;
 use oxigraph::sparql::QueryResults;
 use oxigraph::sparql::QuerySolution;
-use oxigraph::store::Store;
-use oxrdfio::{RdfFormat, RdfParser};
-use std::fs;
-use std::io;
-use std::path::PathBuf;
 
-/// Perform a query over a set of RDF file.
-pub(crate) fn query_files(files: Vec<&PathBuf>, query: &'static str) -> Result<QueryResults, Error> {
-    let store = Store::new()?;
-
-    let rdf_strings: Vec<Result<String, io::Error>> = files
-        .into_iter()
-        .map(|file| fs::read_to_string(file))
-        .collect();
-
-    let mut triples = String::new();
-
-    for result_rdf_string in rdf_strings {
-        let rdf_strings = result_rdf_string?;
-        triples.push_str("\n");
-        triples.push_str(&rdf_strings);
-    }
-    let turtle_parser = RdfParser::from_format(RdfFormat::Turtle);
-
-    let quads: Vec<Result<Quad, RdfSyntaxError>> =
-        turtle_parser.for_slice(triples.as_bytes()).collect();
-
-    for quad_restults in quads {
-        let quad = quad_restults?;
-        let _ = store.insert(&quad)?;
-    }
-
-    let query_result = store.query(query)?;
-    Ok(query_result)
-}
-
-/// Create a prolog knowledge base using RDF files, a query and a function to generate fact using the bingings of the query.
-pub (crate) fn create_prolog_knowledge_base(
-    files: Vec<&PathBuf>,
+/// Query a dataset and build a value of the model from each solution of the query.
+pub(crate) fn query_model<T>(
+    dataset: &Dataset,
     query: &'static str,
-    generate_a_prolog_fact: fn(QuerySolution) -> Result<String, Error>,
-) -> Result<Vec<String>, Error> {
-    let mut prolog_knowledge_base: Vec<String> = Vec::new();
+    from_solution: impl Fn(QuerySolution) -> Result<T, Error>,
+) -> Result<Vec<T>, Error> {
+    let mut values: Vec<T> = Vec::new();
 
-    let query_result = query_files(files, query)?;
+    let query_result = dataset.query(query)?;
 
     if let QueryResults::Solutions(solution_maps) = query_result {
         for result_solution_map in solution_maps {
             let solution_map = result_solution_map?;
-            let prolog_fact = generate_a_prolog_fact(solution_map)?;
-            prolog_knowledge_base.push(prolog_fact);
+            values.push(from_solution(solution_map)?);
         }
+    } else {
+        return Err(ErrorNotSelectQuery.into());
     }
 
-    Ok(prolog_knowledge_base)
+    Ok(values)
+}
+
+/// Create a prolog knowledge base using values of the model and a function to generate a fact from a value.
+pub(crate) fn create_prolog_knowledge_base<T>(
+    values: &[T],
+    generate_a_prolog_fact: impl Fn(&T) -> PrologTerm,
+) -> Vec<PrologTerm> {
+    values.iter().map(generate_a_prolog_fact).collect()
+}
+
+/// Get the value bound to a projected variable of a solution map.
+pub(crate) fn solution_variable<'a>(
+    solution_map: &'a QuerySolution,
+    variable: &'static str,
+) -> Result<&'a Term, ErrorProjectionVariableDoesNotExist> {
+    solution_map
+        .get(variable)
+        .ok_or_else(|| ErrorProjectionVariableDoesNotExist {
+            variable: variable.to_string(),
+        })
+}
+
+pub(crate) fn named_node_to_string(
+    term: &Term,
+    variable: &'static str,
+) -> Result<String, ErrorSolutionExpectedToBeNamedNode> {
+    if let Term::NamedNode(named_node) = term {
+        Ok(named_node.as_str().to_string())
+    } else {
+        Err(ErrorSolutionExpectedToBeNamedNode { variable })
+    }
 }
 
 pub(crate) fn literal_string_to_string(
@@ -90,6 +90,77 @@ pub(crate) fn literal_string_to_string(
     }
 }
 
+/// Convert a literal containing an integer into an `i64`.
+pub(crate) fn literal_integer_to_i64(
+    term: &Term,
+    variable: &'static str,
+) -> Result<i64, ErrorSolutionExpectedToBeInteger> {
+    if let Term::Literal(literal) = term {
+        if let Ok(integer) = literal.value().parse::<i64>() {
+            return Ok(integer);
+        }
+    }
+    Err(ErrorSolutionExpectedToBeInteger { variable })
+}
+
+/// Convert an integer literal into a phase of Kagutsuchi.
+pub(crate) fn literal_kagutsuchi_phase(
+    term: &Term,
+    variable: &'static str,
+) -> Result<KagutsuchiPhase, Error> {
+    Ok(KagutsuchiPhase::new(literal_integer_to_i64(term, variable)?)?)
+}
+
+/// Convert a `xsd:boolean` literal or a string literal containing a boolean into a `bool`.
+pub(crate) fn literal_boolean_to_bool(
+    term: &Term,
+    variable: &'static str,
+) -> Result<bool, ErrorSolutionExpectedToBeBoolean> {
+    if let Term::Literal(literal) = term {
+        let datatype = literal.datatype();
+        if datatype == xsd::BOOLEAN || datatype == xsd::STRING {
+            match literal.value() {
+                "true" | "1" if datatype == xsd::BOOLEAN => return Ok(true),
+                "false" | "0" if datatype == xsd::BOOLEAN => return Ok(false),
+                "true" => return Ok(true),
+                "false" => return Ok(false),
+                _ => (),
+            }
+        }
+    }
+    Err(ErrorSolutionExpectedToBeBoolean { variable })
+}
+
+#[cfg(test)]
+mod named_node_to_string_test {
+    use super::*;
+
+    #[test]
+    fn should_return_an_error_if_the_term_is_not_a_named_node() {
+        let terms: Vec<Term> = vec![
+            Term::BlankNode(BlankNode::default()),
+            Term::Literal(Literal::from("a")),
+        ];
+        for term in terms {
+            let res = named_node_to_string(&term, "boo");
+            assert!(res.is_err());
+            let error = res.unwrap_err();
+            assert_eq!(error.variable, "boo");
+        }
+    }
+
+    #[test]
+    fn should_return_the_iri_of_a_named_node() -> Result<(), Error> {
+        let a_named_node: NamedNode = NamedNode::new("http://example.com")?;
+
+        let res = named_node_to_string(&Term::NamedNode(a_named_node), "foo")?;
+
+        assert_eq!(res, "http://example.com".to_string());
+
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod literal_string_to_string_test {
     use super::*;
@@ -119,3 +190,111 @@ mod literal_string_to_string_test {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod literal_integer_to_i64_test {
+    use super::*;
+
+    #[test]
+    fn should_return_an_error_if_the_term_is_not_a_literal() -> Result<(), Error> {
+        let a_blank_node: BlankNode = BlankNode::default();
+        let a_named_node: NamedNode = NamedNode::new("http:example.com")?;
+        let terms: Vec<Term> = vec![Term::BlankNode(a_blank_node), Term::NamedNode(a_named_node)];
+        for term in terms {
+            let res = literal_integer_to_i64(&term, "boo");
+            assert!(res.is_err());
+            let error = res.unwrap_err();
+            assert_eq!(error.variable, "boo");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_an_error_if_the_literal_is_not_an_integer() {
+        let res = literal_integer_to_i64(&Term::Literal(Literal::from("1.5")), "foo");
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn should_return_an_integer_of_a_literal() -> Result<(), Error> {
+        assert_eq!(literal_integer_to_i64(&Term::Literal(Literal::from(69)), "foo")?, 69);
+        assert_eq!(literal_integer_to_i64(&Term::Literal(Literal::from("-2")), "foo")?, -2);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod literal_kagutsuchi_phase_test {
+    use super::*;
+
+    #[test]
+    fn should_return_a_phase_of_a_literal() -> Result<(), Error> {
+        assert_eq!(
+            literal_kagutsuchi_phase(&Term::Literal(Literal::from(8)), "foo")?,
+            KagutsuchiPhase::FULL
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_an_error_if_the_literal_is_not_a_phase() {
+        let res = literal_kagutsuchi_phase(&Term::Literal(Literal::from(9)), "foo");
+        assert!(res.is_err());
+
+        let res = literal_kagutsuchi_phase(&Term::Literal(Literal::from("full")), "foo");
+        assert!(res.is_err());
+    }
+}
+
+#[cfg(test)]
+mod literal_boolean_to_bool_test {
+    use super::*;
+
+    #[test]
+    fn should_return_an_error_if_the_term_is_not_a_literal() -> Result<(), Error> {
+        let a_blank_node: BlankNode = BlankNode::default();
+        let a_named_node: NamedNode = NamedNode::new("http:example.com")?;
+        let terms: Vec<Term> = vec![Term::BlankNode(a_blank_node), Term::NamedNode(a_named_node)];
+        for term in terms {
+            let res = literal_boolean_to_bool(&term, "boo");
+            assert!(res.is_err());
+            let error = res.unwrap_err();
+            assert_eq!(error.variable, "boo");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_an_error_if_the_literal_is_not_a_boolean() {
+        let literals: Vec<Literal> = vec![
+            Literal::from("bar"),
+            Literal::from(1),
+            Literal::new_typed_literal("yes", xsd::BOOLEAN),
+        ];
+        for literal in literals {
+            let res = literal_boolean_to_bool(&Term::Literal(literal), "foo");
+            assert!(res.is_err());
+        }
+    }
+
+    #[test]
+    fn should_return_a_bool_of_a_boolean_literal() -> Result<(), Error> {
+        assert!(literal_boolean_to_bool(&Term::Literal(Literal::from(true)), "foo")?);
+        assert!(!literal_boolean_to_bool(&Term::Literal(Literal::from(false)), "foo")?);
+        assert!(literal_boolean_to_bool(
+            &Term::Literal(Literal::new_typed_literal("1", xsd::BOOLEAN)),
+            "foo"
+        )?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_a_bool_of_a_string_boolean_literal() -> Result<(), Error> {
+        assert!(literal_boolean_to_bool(&Term::Literal(Literal::from("true")), "foo")?);
+        assert!(!literal_boolean_to_bool(&Term::Literal(Literal::from("false")), "foo")?);
+
+        Ok(())
+    }
+}